@@ -9,6 +9,7 @@ let package = Package(
     ],
     products: [
         .executable(name: "FilesMindApp", targets: ["FilesMindApp"]),
+        .executable(name: "filesmind", targets: ["FilesMindCLI"]),
         .library(name: "Domain", targets: ["Domain"]),
         .library(name: "TelemetryKit", targets: ["TelemetryKit"]),
         .library(name: "SecurityKit", targets: ["SecurityKit"]),
@@ -32,6 +33,18 @@ let package = Package(
                 "Domain",
                 "GraphEngine",
                 "DesignSystem"
+            ],
+            resources: [
+                .process("Resources")
+            ]
+        ),
+        .executableTarget(
+            name: "FilesMindCLI",
+            dependencies: [
+                "AppCore",
+                "Domain",
+                "SearchKit",
+                "TelemetryKit"
             ]
         ),
         .target(name: "Domain"),
@@ -66,10 +79,12 @@ let package = Package(
             ]
         ),
         .testTarget(name: "DomainTests", dependencies: ["Domain"]),
-        .testTarget(name: "AppCoreTests", dependencies: ["AppCore", "Domain"]),
+        .testTarget(name: "AppCoreTests", dependencies: ["AppCore", "Domain", "SecurityKit"]),
         .testTarget(name: "DocumentPipelineTests", dependencies: ["DocumentPipeline", "Domain"]),
         .testTarget(name: "StorageKitTests", dependencies: ["StorageKit", "Domain"]),
         .testTarget(name: "SecurityKitTests", dependencies: ["SecurityKit", "Domain"]),
-        .testTarget(name: "GraphEngineTests", dependencies: ["GraphEngine", "Domain"])
+        .testTarget(name: "SearchKitTests", dependencies: ["SearchKit", "Domain"]),
+        .testTarget(name: "GraphEngineTests", dependencies: ["GraphEngine", "Domain"]),
+        .testTarget(name: "ModelManagerTests", dependencies: ["ModelManager", "Domain"])
     ]
 )
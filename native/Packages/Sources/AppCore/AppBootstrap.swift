@@ -18,8 +18,11 @@ public struct AppContainer: Sendable {
     public let lowQualityReparseQueue: LowQualityReparseQueue
     public let pipelineRouter: PipelineRouter
     public let modelManager: ModelManaging
+    public let networkStatus: any NetworkStatusProviding
     public let cognitiveEngine: CognitiveEngine
     public let importQueue: ImportQueue
+    public let backupScheduler: DatabaseBackupScheduler?
+    public let documentPasswords: any DocumentPasswordStoring
 
     public init(
         telemetry: Telemetry,
@@ -30,8 +33,11 @@ public struct AppContainer: Sendable {
         lowQualityReparseQueue: LowQualityReparseQueue,
         pipelineRouter: PipelineRouter,
         modelManager: ModelManaging,
+        networkStatus: any NetworkStatusProviding,
         cognitiveEngine: CognitiveEngine,
-        importQueue: ImportQueue
+        importQueue: ImportQueue,
+        backupScheduler: DatabaseBackupScheduler?,
+        documentPasswords: any DocumentPasswordStoring
     ) {
         self.telemetry = telemetry
         self.bookmarkManager = bookmarkManager
@@ -41,14 +47,37 @@ public struct AppContainer: Sendable {
         self.lowQualityReparseQueue = lowQualityReparseQueue
         self.pipelineRouter = pipelineRouter
         self.modelManager = modelManager
+        self.networkStatus = networkStatus
         self.cognitiveEngine = cognitiveEngine
         self.importQueue = importQueue
+        self.backupScheduler = backupScheduler
+        self.documentPasswords = documentPasswords
+    }
+}
+
+public extension AppContainer {
+    /// Moves later model downloads onto `proxyOverride`, or back to the system settings when it is nil.
+    func applyProxyOverride(_ proxyOverride: ProxyOverride?) async {
+        guard let manager = modelManager as? any DownloadSessionReplaceable else { return }
+        await manager.replaceDownloadSession(ProxyOverride.downloadSession(for: proxyOverride))
+        telemetry.info("Model download proxy changed: \(proxyOverride?.hostAndPort ?? "system settings")")
+    }
+
+    /// Caps model download bandwidth, including a download in progress; nil removes the cap.
+    func applyDownloadLimit(bytesPerSecond: Int?) async {
+        guard let manager = modelManager as? any DownloadThrottling else { return }
+        await manager.setMaxDownloadBytesPerSecond(bytesPerSecond)
+        telemetry.info("Model download limit changed: \(bytesPerSecond.map { "\($0) B/s" } ?? "none")")
     }
 }
 
 public enum AppBootstrap {
-    public static func makeDefault(installRoot: URL) -> AppContainer {
-        let telemetry = ConsoleTelemetry()
+    public static func makeDefault(
+        installRoot: URL,
+        telemetry: Telemetry = ConsoleTelemetry(),
+        proxyOverride: ProxyOverride? = nil,
+        maxDownloadBytesPerSecond: Int? = nil
+    ) -> AppContainer {
         let bookmarkStore = UserDefaultsBookmarkStore()
         let bookmarkManager = SecurityScopedBookmarkManager(store: bookmarkStore, telemetry: telemetry)
         let chunkRepository: any ChunkRepository & EmbeddingSearchRepository
@@ -66,7 +95,8 @@ public enum AppBootstrap {
             telemetry: telemetry
         )
         let router = PipelineRouter(telemetry: telemetry)
-        let parser = DefaultDocumentParser(router: router, telemetry: telemetry)
+        let documentPasswords = DocumentPasswordVault(telemetry: telemetry)
+        let parser = DefaultDocumentParser(router: router, passwordProvider: documentPasswords, telemetry: telemetry)
         let documentStore = chunkRepository as? any ImportedDocumentStore
         let importer = DocumentImportService(
             parser: parser,
@@ -83,15 +113,26 @@ public enum AppBootstrap {
 
         let catalog = StaticModelCatalog(models: [])
         let validator = SHA256ArtifactValidator()
+        let networkStatus = NetworkPathMonitor(telemetry: telemetry)
+        if let proxyOverride {
+            telemetry.info("Model downloads use proxy override: \(proxyOverride.hostAndPort)")
+        }
         let modelManager = DefaultModelManager(
             catalog: catalog,
             validator: validator,
             installRoot: installRoot,
+            session: ProxyOverride.downloadSession(for: proxyOverride),
+            maxDownloadBytesPerSecond: maxDownloadBytesPerSecond,
+            networkStatus: networkStatus,
             telemetry: telemetry
         )
 
         let engine = StubCognitiveEngine(telemetry: telemetry)
-        let importQueue = ImportQueue(importer: importer, telemetry: telemetry)
+        let importQueue = ImportQueue(
+            importer: importer,
+            failedImportStore: chunkRepository as? any FailedImportStore,
+            telemetry: telemetry
+        )
 
         return AppContainer(
             telemetry: telemetry,
@@ -102,8 +143,13 @@ public enum AppBootstrap {
             lowQualityReparseQueue: lowQualityReparseQueue,
             pipelineRouter: router,
             modelManager: modelManager,
+            networkStatus: networkStatus,
             cognitiveEngine: engine,
-            importQueue: importQueue
+            importQueue: importQueue,
+            backupScheduler: (chunkRepository as? any BackupCapableStore).map {
+                DatabaseBackupScheduler(store: $0, telemetry: telemetry)
+            },
+            documentPasswords: documentPasswords
         )
     }
 }
@@ -119,3 +165,20 @@ public actor SelectWorkspaceUseCase {
         try await bookmarkManager.authorizeWorkspace(id: workspaceID, directoryURL: directoryURL)
     }
 }
+
+public actor RestoreWorkspaceUseCase {
+    private let bookmarkManager: WorkspaceAuthorizationManaging
+
+    public init(bookmarkManager: WorkspaceAuthorizationManaging) {
+        self.bookmarkManager = bookmarkManager
+    }
+
+    /// Resolves the saved bookmark and starts scoped access; nil when no workspace was ever chosen.
+    public func execute(workspaceID: WorkspaceID) async throws -> WorkspaceAccessHandle? {
+        do {
+            return try await bookmarkManager.startScopedAccess(id: workspaceID)
+        } catch FilesMindError.notFound(_) {
+            return nil
+        }
+    }
+}
@@ -0,0 +1,40 @@
+import Domain
+import Foundation
+
+/// Files for clipboard captures, kept alongside the library so imported captures keep a source to open.
+public struct ClipboardCaptureStore: Sendable {
+    public let directoryURL: URL
+
+    public init(directoryURL: URL) {
+        self.directoryURL = directoryURL
+    }
+
+    public func writeText(_ text: String, capturedAt: Date = Date()) throws -> URL {
+        let trimmed = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmed.isEmpty else {
+            throw FilesMindError.validationFailed("Clipboard text is empty")
+        }
+
+        let fileURL = try nextFileURL(pathExtension: "md", capturedAt: capturedAt)
+        try Data((trimmed + "\n").utf8).write(to: fileURL, options: .withoutOverwriting)
+        return fileURL
+    }
+
+    /// Names read like screenshots ("Clipboard 2026-10-14 at 15.30.12.md"); same-second captures get a counter.
+    public func nextFileURL(pathExtension: String, capturedAt: Date = Date()) throws -> URL {
+        try FileManager.default.createDirectory(at: directoryURL, withIntermediateDirectories: true)
+
+        let formatter = DateFormatter()
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.dateFormat = "yyyy-MM-dd 'at' HH.mm.ss"
+        let baseName = "Clipboard \(formatter.string(from: capturedAt))"
+
+        var candidate = directoryURL.appendingPathComponent("\(baseName).\(pathExtension)", isDirectory: false)
+        var counter = 2
+        while FileManager.default.fileExists(atPath: candidate.path) {
+            candidate = directoryURL.appendingPathComponent("\(baseName) \(counter).\(pathExtension)", isDirectory: false)
+            counter += 1
+        }
+        return candidate
+    }
+}
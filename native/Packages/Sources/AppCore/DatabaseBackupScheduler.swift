@@ -0,0 +1,252 @@
+import Domain
+import Foundation
+import TelemetryKit
+
+public struct BackupConfiguration: Sendable, Equatable {
+    public let interval: TimeInterval
+    public let destinationURL: URL
+    public let retainCount: Int
+
+    public init(interval: TimeInterval = 24 * 60 * 60, destinationURL: URL, retainCount: Int = 7) {
+        self.interval = max(60, interval)
+        self.destinationURL = destinationURL
+        self.retainCount = max(1, retainCount)
+    }
+}
+
+public enum BackupEvent: Sendable, Equatable {
+    case completed(URL)
+    case restored(URL)
+    case failed(String)
+}
+
+/// Periodically snapshots the library database into timestamped files and prunes all but the newest few.
+public actor DatabaseBackupScheduler {
+    static let filePrefix = "FilesMind-"
+    static let fileExtension = "sqlite"
+
+    private let store: any BackupCapableStore
+    private let now: @Sendable () -> Date
+    private let telemetry: Telemetry
+    private var configuration: BackupConfiguration?
+    private var scheduleTask: Task<Void, Never>?
+    private var lastFailedAttempt: Date?
+    private var runningBackups = 0
+    private var isSuspended = false
+    private var backupWaiters: [CheckedContinuation<Void, Never>] = []
+    private var continuations: [UUID: AsyncStream<BackupEvent>.Continuation] = [:]
+
+    public init(
+        store: any BackupCapableStore,
+        now: @escaping @Sendable () -> Date = Date.init,
+        telemetry: Telemetry = ConsoleTelemetry()
+    ) {
+        self.store = store
+        self.now = now
+        self.telemetry = telemetry
+    }
+
+    /// Passing nil stops scheduled backups; existing archives are left alone.
+    public func configure(_ configuration: BackupConfiguration?) {
+        scheduleTask?.cancel()
+        scheduleTask = nil
+        self.configuration = configuration
+        guard let configuration else { return }
+
+        scheduleTask = Task { [weak self] in
+            while !Task.isCancelled {
+                guard let delay = await self?.secondsUntilNextBackup() else { return }
+                if delay > 0 {
+                    try? await Task.sleep(for: .seconds(delay))
+                }
+                guard !Task.isCancelled, let self else { return }
+                _ = try? await self.backupNow()
+            }
+        }
+        telemetry.info("Scheduled library backups every \(Int(configuration.interval))s to \(configuration.destinationURL.path)")
+    }
+
+    /// One interval after the newest archive, so relaunching doesn't push the schedule back; now if none exists yet.
+    /// A failed attempt also waits out a full interval instead of retrying in a loop.
+    public func nextBackupDate() -> Date? {
+        guard let configuration else { return nil }
+        let newestArchiveDate = (try? Self.archives(in: configuration.destinationURL))?
+            .lazy
+            .compactMap { Self.date(ofArchive: $0) }
+            .first
+        let lastAttempt = [newestArchiveDate, lastFailedAttempt].compactMap { $0 }.max()
+        return lastAttempt.map { $0.addingTimeInterval(configuration.interval) } ?? now()
+    }
+
+    public func subscribe() -> AsyncStream<BackupEvent> {
+        let id = UUID()
+        return AsyncStream { continuation in
+            continuations[id] = continuation
+            continuation.onTermination = { [weak self] _ in
+                Task { await self?.removeContinuation(id) }
+            }
+        }
+    }
+
+    @discardableResult
+    public func backupNow() async throws -> URL {
+        guard let configuration else {
+            throw FilesMindError.invalidState("Backups are not configured")
+        }
+        guard !isSuspended else {
+            throw FilesMindError.invalidState("Backups are paused while the library is restored or reset")
+        }
+        runningBackups += 1
+        defer { finishBackup() }
+
+        let directory = configuration.destinationURL
+        let archiveURL = directory.appendingPathComponent(
+            "\(Self.filePrefix)\(Self.timestamp(for: now())).\(Self.fileExtension)",
+            isDirectory: false
+        )
+        let partialURL = directory.appendingPathComponent(".\(archiveURL.lastPathComponent).partial", isDirectory: false)
+
+        do {
+            try? FileManager.default.removeItem(at: partialURL)
+            try await store.writeBackup(to: partialURL)
+            if FileManager.default.fileExists(atPath: archiveURL.path) {
+                try FileManager.default.removeItem(at: archiveURL)
+            }
+            try FileManager.default.moveItem(at: partialURL, to: archiveURL)
+            try prune(in: directory, keeping: configuration.retainCount)
+        } catch {
+            try? FileManager.default.removeItem(at: partialURL)
+            lastFailedAttempt = now()
+            telemetry.error("Library backup failed: \(error.localizedDescription)")
+            broadcast(.failed(error.localizedDescription))
+            throw error
+        }
+
+        lastFailedAttempt = nil
+        telemetry.info("Library backup completed: \(archiveURL.lastPathComponent)")
+        broadcast(.completed(archiveURL))
+        return archiveURL
+    }
+
+    /// Stops the schedule and waits for a backup in progress before replacing the store, then picks the schedule
+    /// back up. Callers drain whatever else writes to the store themselves.
+    public func restore(from backupURL: URL) async throws {
+        try await withScheduleSuspended {
+            do {
+                try await store.restoreBackup(from: backupURL)
+            } catch {
+                telemetry.error("Library restore failed: \(error.localizedDescription)")
+                broadcast(.failed(error.localizedDescription))
+                throw error
+            }
+
+            telemetry.info("Library restored from backup: \(backupURL.lastPathComponent)")
+            broadcast(.restored(backupURL))
+        }
+    }
+
+    /// Deletes this scheduler's archives and leftover partial files once a backup in progress has finished. The
+    /// destination may be a folder the user chose, so anything else in it is left alone. With no archives left, the
+    /// schedule takes the next backup right away.
+    public func removeAllBackups() async throws {
+        guard let directory = configuration?.destinationURL else { return }
+        try await withScheduleSuspended {
+            for fileURL in try Self.archives(in: directory) + Self.partialFiles(in: directory) {
+                try FileManager.default.removeItem(at: fileURL)
+            }
+            lastFailedAttempt = nil
+            telemetry.info("Removed all library backups in \(directory.path)")
+        }
+    }
+
+    /// Newest first.
+    public func backups() throws -> [URL] {
+        guard let configuration else { return [] }
+        return try Self.archives(in: configuration.destinationURL)
+    }
+
+    static func archives(in directory: URL) throws -> [URL] {
+        guard FileManager.default.fileExists(atPath: directory.path) else { return [] }
+        return try FileManager.default
+            .contentsOfDirectory(at: directory, includingPropertiesForKeys: nil, options: [.skipsHiddenFiles])
+            .filter { $0.lastPathComponent.hasPrefix(filePrefix) && $0.pathExtension == fileExtension }
+            // Timestamps are zero-padded, so name order is chronological.
+            .sorted(by: { $0.lastPathComponent > $1.lastPathComponent })
+    }
+
+    private static func partialFiles(in directory: URL) throws -> [URL] {
+        guard FileManager.default.fileExists(atPath: directory.path) else { return [] }
+        return try FileManager.default
+            .contentsOfDirectory(at: directory, includingPropertiesForKeys: nil)
+            .filter { $0.lastPathComponent.hasPrefix(".\(filePrefix)") && $0.pathExtension == "partial" }
+    }
+
+    private func prune(in directory: URL, keeping retainCount: Int) throws {
+        for archive in try Self.archives(in: directory).dropFirst(retainCount) {
+            try FileManager.default.removeItem(at: archive)
+            telemetry.info("Pruned library backup: \(archive.lastPathComponent)")
+        }
+    }
+
+    private func withScheduleSuspended(_ operation: () async throws -> Void) async throws {
+        guard !isSuspended else {
+            throw FilesMindError.invalidState("The library is already being restored or reset")
+        }
+        isSuspended = true
+        let scheduledConfiguration = configuration
+        scheduleTask?.cancel()
+        scheduleTask = nil
+        defer {
+            isSuspended = false
+            if let scheduledConfiguration, configuration == scheduledConfiguration, scheduleTask == nil {
+                configure(scheduledConfiguration)
+            }
+        }
+        if runningBackups > 0 {
+            await withCheckedContinuation { continuation in
+                backupWaiters.append(continuation)
+            }
+        }
+        try await operation()
+    }
+
+    private func finishBackup() {
+        runningBackups -= 1
+        guard runningBackups == 0 else { return }
+        let waiters = backupWaiters
+        backupWaiters = []
+        waiters.forEach { $0.resume() }
+    }
+
+    private func secondsUntilNextBackup() -> TimeInterval? {
+        nextBackupDate().map { $0.timeIntervalSince(now()) }
+    }
+
+    private func broadcast(_ event: BackupEvent) {
+        for continuation in continuations.values {
+            continuation.yield(event)
+        }
+    }
+
+    private func removeContinuation(_ id: UUID) {
+        continuations[id] = nil
+    }
+
+    private static func timestamp(for date: Date) -> String {
+        makeTimestampFormatter().string(from: date)
+    }
+
+    private static func date(ofArchive archiveURL: URL) -> Date? {
+        let name = archiveURL.deletingPathExtension().lastPathComponent
+        guard name.hasPrefix(filePrefix) else { return nil }
+        return makeTimestampFormatter().date(from: String(name.dropFirst(filePrefix.count)))
+    }
+
+    private static func makeTimestampFormatter() -> DateFormatter {
+        let formatter = DateFormatter()
+        formatter.locale = Locale(identifier: "en_US_POSIX")
+        formatter.timeZone = TimeZone(identifier: "UTC")
+        formatter.dateFormat = "yyyyMMdd'T'HHmmss'Z'"
+        return formatter
+    }
+}
@@ -0,0 +1,58 @@
+import Foundation
+
+public enum DeepLink: Sendable, Equatable {
+    case openDocument(UUID)
+    case search(String)
+
+    public static let scheme = "filesmind"
+
+    private static let queryValueAllowed: CharacterSet = {
+        var allowed = CharacterSet.urlQueryAllowed
+        allowed.remove(charactersIn: "&=+#")
+        return allowed
+    }()
+
+    /// Accepts `filesmind://open?doc=<uuid>` and `filesmind://search?q=<query>`.
+    public init?(url: URL) {
+        guard
+            url.scheme?.lowercased() == Self.scheme,
+            let components = URLComponents(url: url, resolvingAgainstBaseURL: false),
+            let action = components.host?.lowercased()
+        else {
+            return nil
+        }
+
+        let items = components.queryItems ?? []
+        func value(_ name: String) -> String? {
+            items.first(where: { $0.name == name })?.value?
+                .trimmingCharacters(in: .whitespacesAndNewlines)
+        }
+
+        switch action {
+        case "open":
+            guard let raw = value("doc"), let documentID = UUID(uuidString: raw) else { return nil }
+            self = .openDocument(documentID)
+        case "search":
+            guard let query = value("q"), !query.isEmpty else { return nil }
+            self = .search(query)
+        default:
+            return nil
+        }
+    }
+
+    public var url: URL? {
+        var components = URLComponents()
+        components.scheme = Self.scheme
+        switch self {
+        case .openDocument(let documentID):
+            components.host = "open"
+            components.queryItems = [URLQueryItem(name: "doc", value: documentID.uuidString)]
+        case .search(let query):
+            components.host = "search"
+            components.percentEncodedQueryItems = [
+                URLQueryItem(name: "q", value: query.addingPercentEncoding(withAllowedCharacters: Self.queryValueAllowed))
+            ]
+        }
+        return components.url
+    }
+}
@@ -27,12 +27,13 @@ public actor DocumentImportService: DocumentImporting {
         if let documentStore {
             let record = ImportedDocumentRecord(
                 id: parsed.documentID,
-                sourcePath: parsed.sourceURL.path(),
+                sourcePath: parsed.sourceURL.path(percentEncoded: false),
                 title: parsed.title,
                 sourceType: parsed.sourceType,
                 chunkCount: parsed.chunks.count,
                 lowQualityPages: parsed.lowQualityPages,
-                importedAt: Date()
+                importedAt: Date(),
+                languageCode: parsed.languageCode
             )
             try await documentStore.upsertDocument(record, sections: parsed.sections)
         }
@@ -0,0 +1,33 @@
+import Domain
+import Foundation
+import SecurityKit
+import TelemetryKit
+
+/// Removes a document for good: its saved password, the overwritten source file and its library rows. Thumbnails and
+/// Spotlight entries belong to the app, which clears them itself.
+public actor EraseDocumentUseCase {
+    private let documentRemover: (any DocumentRemoving)?
+    private let passwords: any DocumentPasswordStoring
+    private let eraser: SecureFileEraser
+    private let telemetry: Telemetry
+
+    public init(
+        documentRemover: (any DocumentRemoving)?,
+        passwords: any DocumentPasswordStoring,
+        eraser: SecureFileEraser = SecureFileEraser(),
+        telemetry: Telemetry = ConsoleTelemetry()
+    ) {
+        self.documentRemover = documentRemover
+        self.passwords = passwords
+        self.eraser = eraser
+        self.telemetry = telemetry
+    }
+
+    /// The file is erased before the library forgets the document, so a failure leaves it listed for another try.
+    public func execute(documentID: UUID, sourceURL: URL) async throws {
+        try await passwords.removePassword(for: sourceURL)
+        try eraser.erase(sourceURL)
+        try await documentRemover?.removeDocument(id: documentID)
+        telemetry.info("Erased document: \(sourceURL.lastPathComponent)")
+    }
+}
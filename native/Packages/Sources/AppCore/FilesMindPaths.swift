@@ -0,0 +1,37 @@
+import Foundation
+
+/// Where FilesMind keeps its files. The app and the CLI both use these, so they share one library and one set of
+/// models.
+public enum FilesMindPaths {
+    public static var modelsRootURL: URL {
+        let base = FileManager.default.urls(for: .applicationSupportDirectory, in: .userDomainMask).first
+            ?? FileManager.default.temporaryDirectory
+        return base
+            .appendingPathComponent("FilesMind", isDirectory: true)
+            .appendingPathComponent("Models", isDirectory: true)
+    }
+
+    public static var backupsRootURL: URL {
+        let base = FileManager.default.urls(for: .applicationSupportDirectory, in: .userDomainMask).first
+            ?? FileManager.default.temporaryDirectory
+        return base
+            .appendingPathComponent("FilesMind", isDirectory: true)
+            .appendingPathComponent("Backups", isDirectory: true)
+    }
+
+    public static var capturesRootURL: URL {
+        let base = FileManager.default.urls(for: .applicationSupportDirectory, in: .userDomainMask).first
+            ?? FileManager.default.temporaryDirectory
+        return base
+            .appendingPathComponent("FilesMind", isDirectory: true)
+            .appendingPathComponent("Captures", isDirectory: true)
+    }
+
+    public static var thumbnailsRootURL: URL {
+        let base = FileManager.default.urls(for: .cachesDirectory, in: .userDomainMask).first
+            ?? FileManager.default.temporaryDirectory
+        return base
+            .appendingPathComponent("FilesMind", isDirectory: true)
+            .appendingPathComponent("Thumbnails", isDirectory: true)
+    }
+}
@@ -0,0 +1,49 @@
+import Domain
+import Foundation
+
+public struct ImportBatchSummary: Sendable, Equatable {
+    public let indexedCount: Int
+    public let failedCount: Int
+    public let lockedCount: Int
+
+    public init(indexedCount: Int, failedCount: Int, lockedCount: Int) {
+        self.indexedCount = indexedCount
+        self.failedCount = failedCount
+        self.lockedCount = lockedCount
+    }
+
+    public var totalCount: Int {
+        indexedCount + failedCount + lockedCount
+    }
+}
+
+/// Groups queue snapshots into batches: a batch starts when any job is queued and ends when the queue drains.
+public struct ImportBatchTracker: Sendable {
+    private var batchJobIDs: Set<UUID> = []
+    public private(set) var fractionCompleted: Double?
+
+    public init() {}
+
+    /// Returns a summary of the batch on the snapshot where its last job finishes.
+    public mutating func update(jobs: [ImportJob]) -> ImportBatchSummary? {
+        let activeIDs = Set(jobs.filter { $0.status == .queued || $0.status == .parsing }.map(\.id))
+        batchJobIDs.formUnion(activeIDs)
+
+        guard activeIDs.isEmpty else {
+            let finished = batchJobIDs.count - batchJobIDs.intersection(activeIDs).count
+            fractionCompleted = Double(finished) / Double(batchJobIDs.count)
+            return nil
+        }
+
+        fractionCompleted = nil
+        guard !batchJobIDs.isEmpty else { return nil }
+
+        let batchJobs = jobs.filter { batchJobIDs.contains($0.id) }
+        batchJobIDs = []
+        return ImportBatchSummary(
+            indexedCount: batchJobs.filter { $0.status == .indexed }.count,
+            failedCount: batchJobs.filter { $0.status == .failed }.count,
+            lockedCount: batchJobs.filter { $0.status == .passwordRequired }.count
+        )
+    }
+}
@@ -0,0 +1,115 @@
+import DocumentPipeline
+import Domain
+import Foundation
+
+public enum ImportRejectionReason: Sendable, Equatable {
+    case unsupportedType(String)
+    case notAFile
+    case unreadable
+    case tooLarge(bytes: Int64)
+}
+
+public struct ImportRejection: Sendable, Equatable {
+    public let fileURL: URL
+    public let reason: ImportRejectionReason
+
+    public init(fileURL: URL, reason: ImportRejectionReason) {
+        self.fileURL = fileURL
+        self.reason = reason
+    }
+}
+
+public struct ImportCandidates: Sendable, Equatable {
+    public let accepted: [URL]
+    public let rejected: [ImportRejection]
+
+    public init(accepted: [URL], rejected: [ImportRejection]) {
+        self.accepted = accepted
+        self.rejected = rejected
+    }
+}
+
+public struct ImportCandidateResolver: Sendable {
+    private let supportedExtensions: Set<String>
+    private let maxFileBytes: Int64
+    private let maxExpandedFiles: Int
+
+    public init(
+        supportedExtensions: Set<String> = DefaultDocumentParser.supportedFileExtensions,
+        maxFileBytes: Int64 = 512 * 1024 * 1024,
+        maxExpandedFiles: Int = 10_000
+    ) {
+        self.supportedExtensions = supportedExtensions
+        self.maxFileBytes = maxFileBytes
+        self.maxExpandedFiles = max(1, maxExpandedFiles)
+    }
+
+    /// Dropped folders are expanded recursively, honoring their `.fmignore`. Unsupported files found inside a
+    /// folder are skipped quietly; only explicitly dropped files report an unsupported type.
+    public func resolveExpandingDirectories(_ urls: [URL], symlinkPolicy: SymlinkPolicy = .skip) -> ImportCandidates {
+        var files: [URL] = []
+        var rejected: [ImportRejection] = []
+
+        for url in urls {
+            let values = try? url.resourceValues(forKeys: [.isDirectoryKey, .isPackageKey])
+            guard values?.isDirectory == true, values?.isPackage != true else {
+                files.append(url)
+                continue
+            }
+
+            let walker = WorkspaceFileWalker(
+                symlinkPolicy: symlinkPolicy,
+                ignoreRules: WorkspaceIgnoreRules.load(in: url)
+            )
+            do {
+                try walker.walk(url) { fileURL, _ in
+                    if supportedExtensions.contains(fileURL.pathExtension.lowercased()) {
+                        files.append(fileURL)
+                    }
+                    return files.count < maxExpandedFiles
+                }
+            } catch {
+                rejected.append(ImportRejection(fileURL: url.standardizedFileURL, reason: .unreadable))
+            }
+        }
+
+        let resolved = resolve(files)
+        return ImportCandidates(accepted: resolved.accepted, rejected: rejected + resolved.rejected)
+    }
+
+    public func resolve(_ urls: [URL]) -> ImportCandidates {
+        var accepted: [URL] = []
+        var rejected: [ImportRejection] = []
+        var seenPaths = Set<String>()
+
+        for url in urls {
+            let fileURL = url.standardizedFileURL
+            guard seenPaths.insert(fileURL.path).inserted else { continue }
+
+            if let reason = rejectionReason(for: fileURL) {
+                rejected.append(ImportRejection(fileURL: fileURL, reason: reason))
+            } else {
+                accepted.append(fileURL)
+            }
+        }
+
+        return ImportCandidates(accepted: accepted, rejected: rejected)
+    }
+
+    private func rejectionReason(for fileURL: URL) -> ImportRejectionReason? {
+        guard fileURL.isFileURL else { return .notAFile }
+
+        let ext = fileURL.pathExtension.lowercased()
+        guard supportedExtensions.contains(ext) else { return .unsupportedType(ext) }
+
+        guard let values = try? fileURL.resourceValues(forKeys: [.isRegularFileKey, .isReadableKey, .fileSizeKey]) else {
+            return .unreadable
+        }
+        guard values.isRegularFile == true else { return .notAFile }
+        guard values.isReadable != false else { return .unreadable }
+
+        let bytes = Int64(values.fileSize ?? 0)
+        guard bytes <= maxFileBytes else { return .tooLarge(bytes: bytes) }
+        return nil
+    }
+}
@@ -2,15 +2,101 @@ import Domain
 import Foundation
 import TelemetryKit
 
+public struct ImportRetryPolicy: Sendable, Equatable {
+    public let maxAttempts: Int
+    public let initialDelay: TimeInterval
+    public let backoffMultiplier: Double
+
+    public init(maxAttempts: Int = 3, initialDelay: TimeInterval = 2, backoffMultiplier: Double = 2) {
+        self.maxAttempts = max(1, maxAttempts)
+        self.initialDelay = max(0, initialDelay)
+        self.backoffMultiplier = max(1, backoffMultiplier)
+    }
+
+    public func delay(afterAttempt attempt: Int) -> TimeInterval {
+        initialDelay * pow(backoffMultiplier, Double(max(0, attempt - 1)))
+    }
+
+    /// Validation and authorization failures will not heal on their own, so they skip straight to the dead-letter list.
+    public func isRetryable(_ error: Error) -> Bool {
+        switch error as? FilesMindError {
+        case .notFound?, .unauthorized?, .validationFailed?, .notSupported?, .passwordRequired?:
+            return false
+        case .invalidState?, nil:
+            return true
+        }
+    }
+}
+
+public struct ImportQueueConfiguration: Sendable, Equatable {
+    public let maxConcurrentJobs: Int
+    public let concurrencyLimits: [ImportPriority: Int]
+    public let starvationThreshold: TimeInterval
+    public let retryPolicy: ImportRetryPolicy
+
+    public init(
+        maxConcurrentJobs: Int = 3,
+        concurrencyLimits: [ImportPriority: Int] = [.userInitiated: 2, .watchedFolder: 1, .bulk: 1],
+        starvationThreshold: TimeInterval = 30,
+        retryPolicy: ImportRetryPolicy = ImportRetryPolicy()
+    ) {
+        self.maxConcurrentJobs = max(1, maxConcurrentJobs)
+        self.concurrencyLimits = concurrencyLimits
+        self.starvationThreshold = max(0, starvationThreshold)
+        self.retryPolicy = retryPolicy
+    }
+
+    public func limit(for priority: ImportPriority) -> Int {
+        max(1, concurrencyLimits[priority] ?? 1)
+    }
+}
+
+public struct ImportQueueStatus: Sendable, Equatable {
+    public let queued: [ImportPriority: Int]
+    public let running: [ImportPriority: Int]
+    public let indexedCount: Int
+    public let failedCount: Int
+
+    public init(
+        queued: [ImportPriority: Int],
+        running: [ImportPriority: Int],
+        indexedCount: Int,
+        failedCount: Int
+    ) {
+        self.queued = queued
+        self.running = running
+        self.indexedCount = indexedCount
+        self.failedCount = failedCount
+    }
+
+    public var queuedCount: Int { queued.values.reduce(0, +) }
+    public var runningCount: Int { running.values.reduce(0, +) }
+}
+
 public actor ImportQueue {
     private var jobs: [UUID: ImportJob] = [:]
     private var orderedIDs: [UUID] = []
+    private var pendingIDs: [UUID] = []
+    private var pendingSince: [UUID: Date] = [:]
+    private var runningCounts: [ImportPriority: Int] = [:]
+    private var concurrencyCap: Int?
+    private var isPaused = false
+    private var drainWaiters: [CheckedContinuation<Void, Never>] = []
     private var continuations: [UUID: AsyncStream<[ImportJob]>.Continuation] = [:]
     private let importer: DocumentImporting
+    private let failedImportStore: (any FailedImportStore)?
+    private let configuration: ImportQueueConfiguration
     private let telemetry: Telemetry
 
-    public init(importer: DocumentImporting, telemetry: Telemetry = ConsoleTelemetry()) {
+    public init(
+        importer: DocumentImporting,
+        failedImportStore: (any FailedImportStore)? = nil,
+        configuration: ImportQueueConfiguration = ImportQueueConfiguration(),
+        telemetry: Telemetry = ConsoleTelemetry()
+    ) {
         self.importer = importer
+        self.failedImportStore = failedImportStore
+        self.configuration = configuration
         self.telemetry = telemetry
     }
 
@@ -29,22 +115,195 @@ public actor ImportQueue {
         }
     }
 
-    public func enqueue(fileURLs: [URL]) {
+    public func enqueue(fileURLs: [URL], priority: ImportPriority = .userInitiated) {
         for fileURL in fileURLs {
-            let job = ImportJob(fileURL: fileURL, status: .queued, progress: 0)
+            let job = ImportJob(fileURL: fileURL, priority: priority, status: .queued, progress: 0)
             jobs[job.id] = job
             orderedIDs.append(job.id)
-            telemetry.info("Enqueued import job: \(fileURL.lastPathComponent)")
+            markPending(job.id)
+            telemetry.info("Enqueued import job: \(fileURL.lastPathComponent), priority=\(priority.rawValue)")
+        }
+        scheduleNext()
+        broadcast()
+    }
+
+    public func status() -> ImportQueueStatus {
+        var queued: [ImportPriority: Int] = [:]
+        for job in jobs.values where job.status == .queued {
+            queued[job.priority, default: 0] += 1
+        }
+
+        let finished = jobs.values
+        return ImportQueueStatus(
+            queued: queued,
+            running: runningCounts.filter { $0.value > 0 },
+            indexedCount: finished.filter { $0.status == .indexed }.count,
+            failedCount: finished.filter { $0.status == .failed }.count
+        )
+    }
+
+    /// Stops starting jobs and returns once the running ones have finished; queued jobs wait for `resume()`.
+    public func pause() async {
+        isPaused = true
+        guard runningCounts.values.reduce(0, +) > 0 else { return }
+        await withCheckedContinuation { continuation in
+            drainWaiters.append(continuation)
+        }
+    }
+
+    public func resume() {
+        guard isPaused else { return }
+        isPaused = false
+        scheduleNext()
+    }
+
+    /// Temporarily runs fewer jobs at once than configured, e.g. under memory pressure; nil lifts the cap.
+    /// Jobs already running are left to finish.
+    public func setConcurrencyCap(_ cap: Int?) {
+        concurrencyCap = cap.map { max(1, $0) }
+        telemetry.info("Import concurrency cap: \(concurrencyCap.map(String.init) ?? "none")")
+        scheduleNext()
+    }
+
+    /// Moves the given queued jobs to the front of their priority class, in the order supplied.
+    /// Running or finished jobs are ignored. Returns the number of jobs that were moved.
+    @discardableResult
+    public func reorder(jobIDs: [UUID]) -> Int {
+        let pendingSet = Set(pendingIDs)
+        var seen = Set<UUID>()
+        let promoted = jobIDs.filter { pendingSet.contains($0) && seen.insert($0).inserted }
+        guard !promoted.isEmpty else { return 0 }
+
+        let promotedSet = Set(promoted)
+        pendingIDs = promoted + pendingIDs.filter { !promotedSet.contains($0) }
+        broadcast()
+        return promoted.count
+    }
+
+    @discardableResult
+    public func setPriority(_ priority: ImportPriority, for jobID: UUID) -> Bool {
+        guard pendingIDs.contains(jobID), var job = jobs[jobID] else { return false }
+        job.priority = priority
+        jobs[jobID] = job
+        scheduleNext()
+        broadcast()
+        return true
+    }
+
+    /// Drops queued jobs, including ones waiting out a retry backoff, optionally limited to one priority class.
+    /// Running jobs are left to finish.
+    @discardableResult
+    public func clear(priority: ImportPriority? = nil) -> Int {
+        let removed = orderedIDs.filter { id in
+            guard let job = jobs[id], job.status == .queued else { return false }
+            guard let priority else { return true }
+            return job.priority == priority
+        }
+        guard !removed.isEmpty else { return 0 }
+
+        let removedSet = Set(removed)
+        pendingIDs.removeAll { removedSet.contains($0) }
+        orderedIDs.removeAll { removedSet.contains($0) }
+        for id in removed {
+            jobs[id] = nil
+            pendingSince[id] = nil
+        }
+
+        telemetry.info("Cleared queued import jobs: \(removed.count)")
+        broadcast()
+        return removed.count
+    }
+
+    /// Re-queues a job that was parked because its document is password protected.
+    @discardableResult
+    public func resumeLockedJob(id: UUID) -> Bool {
+        guard var job = jobs[id], job.status == .passwordRequired else { return false }
+        job.status = .queued
+        job.progress = 0
+        job.message = "Queued"
+        job.attemptCount = 0
+        jobs[id] = job
+        markPending(id)
+        scheduleNext()
+        broadcast()
+        return true
+    }
+
+    public func failedImports() async throws -> [FailedImportRecord] {
+        guard let failedImportStore else { return [] }
+        return try await failedImportStore.failedImports()
+    }
+
+    /// Moves a dead-lettered import back into the queue with a fresh attempt budget.
+    @discardableResult
+    public func retryFailedImport(id: UUID) async throws -> Bool {
+        guard
+            let failedImportStore,
+            let record = try await failedImportStore.failedImports().first(where: { $0.id == id })
+        else {
+            return false
+        }
+
+        try await failedImportStore.removeFailedImport(id: id)
+        removeJob(id)
+        enqueue(fileURLs: [record.fileURL], priority: record.priority)
+        return true
+    }
+
+    public func dismissFailedImport(id: UUID) async throws {
+        try await failedImportStore?.removeFailedImport(id: id)
+        removeJob(id)
+        broadcast()
+    }
+
+    private func scheduleNext() {
+        guard !isPaused else { return }
+        let now = Date()
+        let maxConcurrentJobs = min(configuration.maxConcurrentJobs, concurrencyCap ?? .max)
+        while runningCounts.values.reduce(0, +) < maxConcurrentJobs {
+            guard let jobID = nextRunnableJobID(now: now), let job = jobs[jobID] else { return }
+            pendingIDs.removeAll { $0 == jobID }
+            pendingSince[jobID] = nil
+            runningCounts[job.priority, default: 0] += 1
             Task {
-                await process(jobID: job.id)
+                await process(jobID: jobID, slot: job.priority)
             }
         }
-        broadcast()
     }
 
-    private func process(jobID: UUID) async {
-        guard let job = jobs[jobID] else { return }
+    private func nextRunnableJobID(now: Date) -> UUID? {
+        var best: (rank: Int, offset: Int, id: UUID)?
+        for (offset, id) in pendingIDs.enumerated() {
+            guard let job = jobs[id] else { continue }
+            guard runningCounts[job.priority, default: 0] < configuration.limit(for: job.priority) else { continue }
+
+            // Jobs that waited past the starvation threshold compete as user-initiated work.
+            let waitingSince = pendingSince[id] ?? job.createdAt
+            let isStarved = now.timeIntervalSince(waitingSince) >= configuration.starvationThreshold
+            let rank = isStarved ? ImportPriority.userInitiated.rank : job.priority.rank
+            if let current = best, (current.rank, current.offset) <= (rank, offset) {
+                continue
+            }
+            best = (rank: rank, offset: offset, id: id)
+        }
+        return best?.id
+    }
+
+    /// `slot` is the priority whose running count `scheduleNext` charged, released even if the job was cleared before it started.
+    private func process(jobID: UUID, slot: ImportPriority) async {
+        defer {
+            runningCounts[slot, default: 1] -= 1
+            if runningCounts.values.reduce(0, +) == 0 {
+                let waiters = drainWaiters
+                drainWaiters = []
+                waiters.forEach { $0.resume() }
+            }
+            scheduleNext()
+        }
+        guard var job = jobs[jobID] else { return }
 
+        job.attemptCount += 1
+        jobs[jobID] = job
         update(jobID: jobID, status: .parsing, progress: 0.2, message: "Parsing \(job.fileURL.lastPathComponent)")
 
         do {
@@ -64,11 +323,76 @@ public actor ImportQueue {
                 message: "Indexed \(parsed.chunks.count) chunks\(fallbackHint)"
             )
         } catch {
-            telemetry.error("Import job failed: \(job.fileURL.lastPathComponent), error=\(error.localizedDescription)")
-            update(jobID: jobID, status: .failed, progress: 1.0, message: error.localizedDescription)
+            await handleFailure(of: job, error: error)
         }
     }
 
+    private func handleFailure(of job: ImportJob, error: Error) async {
+        let policy = configuration.retryPolicy
+        let fileName = job.fileURL.lastPathComponent
+
+        if case .passwordRequired? = error as? FilesMindError {
+            telemetry.warning("Import job waiting for password: \(fileName)")
+            update(jobID: job.id, status: .passwordRequired, progress: 0, message: "Password required")
+            return
+        }
+
+        if policy.isRetryable(error), job.attemptCount < policy.maxAttempts {
+            let delay = policy.delay(afterAttempt: job.attemptCount)
+            telemetry.warning(
+                "Import job failed, retrying: \(fileName), attempt=\(job.attemptCount)/\(policy.maxAttempts), error=\(error.localizedDescription)"
+            )
+            update(
+                jobID: job.id,
+                status: .queued,
+                progress: 0,
+                message: "Retry \(job.attemptCount + 1)/\(policy.maxAttempts) in \(Int(delay.rounded(.up)))s: \(error.localizedDescription)"
+            )
+            Task {
+                try? await Task.sleep(for: .milliseconds(Int(delay * 1000)))
+                await requeue(jobID: job.id)
+            }
+            return
+        }
+
+        telemetry.error("Import job failed: \(fileName), attempts=\(job.attemptCount), error=\(error.localizedDescription)")
+        update(jobID: job.id, status: .failed, progress: 1.0, message: error.localizedDescription)
+
+        guard let failedImportStore else { return }
+        let record = FailedImportRecord(
+            id: job.id,
+            fileURL: job.fileURL,
+            priority: job.priority,
+            attemptCount: job.attemptCount,
+            lastError: error.localizedDescription
+        )
+        do {
+            try await failedImportStore.upsertFailedImport(record)
+        } catch {
+            telemetry.error("Unable to record failed import: \(fileName), error=\(error.localizedDescription)")
+        }
+    }
+
+    private func requeue(jobID: UUID) {
+        // The job may have been cleared while it was waiting out its backoff.
+        guard let job = jobs[jobID], job.status == .queued, !pendingIDs.contains(jobID) else { return }
+        markPending(jobID)
+        scheduleNext()
+        broadcast()
+    }
+
+    private func markPending(_ jobID: UUID) {
+        pendingIDs.append(jobID)
+        pendingSince[jobID] = Date()
+    }
+
+    private func removeJob(_ jobID: UUID) {
+        jobs[jobID] = nil
+        pendingSince[jobID] = nil
+        pendingIDs.removeAll { $0 == jobID }
+        orderedIDs.removeAll { $0 == jobID }
+    }
+
     private func update(jobID: UUID, status: ImportJobStatus, progress: Double, message: String?) {
         guard var job = jobs[jobID] else { return }
         job.status = status
@@ -8,8 +8,8 @@ public actor EnqueueImportUseCase {
         self.queue = queue
     }
 
-    public func execute(fileURLs: [URL]) async {
-        await queue.enqueue(fileURLs: fileURLs)
+    public func execute(fileURLs: [URL], priority: ImportPriority = .userInitiated) async {
+        await queue.enqueue(fileURLs: fileURLs, priority: priority)
     }
 }
 
@@ -6,6 +6,10 @@ public actor LowQualityReparseQueue {
     private var jobs: [UUID: ReparseJob] = [:]
     private var orderedJobIDs: [UUID] = []
     private var continuations: [UUID: AsyncStream<[ReparseJob]>.Continuation] = [:]
+    private var deferredStarts: [(jobID: UUID, document: ImportedDocumentRecord)] = []
+    private var runningCount = 0
+    private var isPaused = false
+    private var drainWaiters: [CheckedContinuation<Void, Never>] = []
 
     private let reparser: LowQualityPageReparsing
     private let documentStore: any ImportedDocumentStore
@@ -63,11 +67,63 @@ public actor LowQualityReparseQueue {
         orderedJobIDs.append(job.id)
         broadcast()
 
+        if isPaused {
+            deferredStarts.append((jobID: job.id, document: document))
+        } else {
+            start(jobID: job.id, document: document)
+        }
+        return true
+    }
+
+    /// Holds new jobs back and returns once the running ones have finished.
+    public func pause() async {
+        isPaused = true
+        guard runningCount > 0 else { return }
+        await withCheckedContinuation { continuation in
+            drainWaiters.append(continuation)
+        }
+    }
+
+    public func resume() {
+        guard isPaused else { return }
+        isPaused = false
+        let starts = deferredStarts
+        deferredStarts = []
+        for (jobID, document) in starts {
+            start(jobID: jobID, document: document)
+        }
+    }
+
+    /// Drops jobs held back by `pause()`. Running jobs are left to finish.
+    @discardableResult
+    public func clear() -> Int {
+        let removed = Set(deferredStarts.map(\.jobID))
+        guard !removed.isEmpty else { return 0 }
+
+        deferredStarts = []
+        orderedJobIDs.removeAll { removed.contains($0) }
+        for id in removed {
+            jobs[id] = nil
+        }
+        telemetry.info("Cleared queued reparse jobs: \(removed.count)")
+        broadcast()
+        return removed.count
+    }
+
+    private func start(jobID: UUID, document: ImportedDocumentRecord) {
+        runningCount += 1
         Task {
-            await process(jobID: job.id, document: document)
+            await process(jobID: jobID, document: document)
+            finishRunningJob()
         }
+    }
 
-        return true
+    private func finishRunningJob() {
+        runningCount -= 1
+        guard runningCount == 0 else { return }
+        let waiters = drainWaiters
+        drainWaiters = []
+        waiters.forEach { $0.resume() }
     }
 
     private func process(jobID: UUID, document: ImportedDocumentRecord) async {
@@ -85,7 +141,8 @@ public actor LowQualityReparseQueue {
                 sourceType: document.sourceType,
                 chunkCount: document.chunkCount,
                 lowQualityPages: remaining,
-                importedAt: Date()
+                importedAt: Date(),
+                languageCode: document.languageCode
             )
 
             try await documentStore.upsertDocument(updatedRecord, sections: sections)
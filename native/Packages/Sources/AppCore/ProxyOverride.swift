@@ -0,0 +1,97 @@
+import CFNetwork
+import Foundation
+
+/// URLSession already follows the system proxy settings, PAC files included. The override is for networks where
+/// model downloads have to take a different proxy than the rest of the Mac.
+public struct ProxyOverride: Sendable, Equatable {
+    public let host: String
+    public let port: Int
+
+    public init(host: String, port: Int) {
+        self.host = host
+        self.port = port
+    }
+
+    /// Accepts `host:port` or an http(s) URL; a missing port defaults to 8080.
+    public init?(string: String) {
+        let trimmed = string.trimmingCharacters(in: .whitespacesAndNewlines)
+        guard !trimmed.isEmpty else { return nil }
+
+        let withScheme = trimmed.contains("://") ? trimmed : "http://\(trimmed)"
+        guard
+            let components = URLComponents(string: withScheme),
+            ["http", "https"].contains(components.scheme?.lowercased() ?? ""),
+            let host = components.host,
+            !host.isEmpty
+        else {
+            return nil
+        }
+        self.init(host: host, port: components.port ?? 8080)
+    }
+
+    public var hostAndPort: String { "\(host):\(port)" }
+
+    /// The shared session when there is no override, since it already follows the system settings.
+    public static func downloadSession(for proxyOverride: ProxyOverride?) -> URLSession {
+        proxyOverride.map { URLSession(configuration: $0.makeSessionConfiguration()) } ?? .shared
+    }
+
+    public func makeSessionConfiguration(base: URLSessionConfiguration = .default) -> URLSessionConfiguration {
+        let configuration = base
+        configuration.connectionProxyDictionary = [
+            kCFNetworkProxiesHTTPEnable: true,
+            kCFNetworkProxiesHTTPProxy: host,
+            kCFNetworkProxiesHTTPPort: port,
+            kCFNetworkProxiesHTTPSEnable: true,
+            kCFNetworkProxiesHTTPSProxy: host,
+            kCFNetworkProxiesHTTPSPort: port
+        ]
+        return configuration
+    }
+}
+
+/// The proxy URLSession picks up from the system settings when there is no override.
+public enum SystemProxySetting: Sendable, Equatable {
+    case direct
+    case manual(ProxyOverride)
+    /// A PAC file, or nil when the proxy is discovered through WPAD.
+    case automatic(URL?)
+
+    public static func current() -> SystemProxySetting {
+        guard let settings = CFNetworkCopySystemProxySettings()?.takeRetainedValue() as? [String: Any] else {
+            return .direct
+        }
+        return SystemProxySetting(settings: settings)
+    }
+
+    /// `settings` has the shape `CFNetworkCopySystemProxySettings()` returns. HTTPS wins over HTTP because model
+    /// downloads are HTTPS.
+    public init(settings: [String: Any]) {
+        if settings[kCFNetworkProxiesProxyAutoConfigEnable as String] as? Int == 1 {
+            let pacURL = (settings[kCFNetworkProxiesProxyAutoConfigURLString as String] as? String).flatMap(URL.init(string:))
+            self = .automatic(pacURL)
+            return
+        }
+        if settings[kCFNetworkProxiesProxyAutoDiscoveryEnable as String] as? Int == 1 {
+            self = .automatic(nil)
+            return
+        }
+
+        let manualKeys = [
+            (kCFNetworkProxiesHTTPSEnable, kCFNetworkProxiesHTTPSProxy, kCFNetworkProxiesHTTPSPort),
+            (kCFNetworkProxiesHTTPEnable, kCFNetworkProxiesHTTPProxy, kCFNetworkProxiesHTTPPort)
+        ]
+        for (enableKey, hostKey, portKey) in manualKeys {
+            guard
+                settings[enableKey as String] as? Int == 1,
+                let host = settings[hostKey as String] as? String,
+                !host.isEmpty
+            else {
+                continue
+            }
+            self = .manual(ProxyOverride(host: host, port: settings[portKey as String] as? Int ?? 8080))
+            return
+        }
+        self = .direct
+    }
+}
@@ -0,0 +1,118 @@
+import Domain
+import Foundation
+import TelemetryKit
+
+public enum LibraryResetItem: Sendable, Hashable {
+    case library
+    case backups
+    case documentPasswords
+    case folder(URL)
+    /// Downloaded model weights, often several gigabytes. Opt-in, since downloading them again takes a while.
+    case models(URL)
+    /// The app's preferences domain, which also holds the saved workspace bookmark. Opt-in.
+    case settings
+}
+
+public struct LibraryResetFailure: Sendable {
+    public let item: LibraryResetItem
+    public let reason: String
+}
+
+public struct LibraryResetReport: Sendable {
+    /// Items that were meant to go but could not be removed.
+    public let failures: [LibraryResetFailure]
+    /// Opt-in items the caller chose to keep.
+    public let kept: [LibraryResetItem]
+
+    /// Everything still on disk after the reset, for the caller to list.
+    public var leftBehind: [LibraryResetItem] {
+        failures.map(\.item) + kept
+    }
+}
+
+/// Deletes what FilesMind keeps about the library. The original documents are not touched. Each part is removed on
+/// its own, so one failure doesn't leave the rest behind, and the report lists whatever is left.
+/// Callers pause the import and re-parse queues first and clear what the app owns, such as Spotlight items.
+public actor ResetLibraryUseCase {
+    private let store: (any LibraryErasing)?
+    private let backupScheduler: DatabaseBackupScheduler?
+    private let passwords: any DocumentPasswordStoring
+    private let folders: [URL]
+    private let modelsFolder: URL
+    private let settings: UserDefaults
+    private let settingsDomain: String?
+    private let telemetry: Telemetry
+
+    public init(
+        store: (any LibraryErasing)?,
+        backupScheduler: DatabaseBackupScheduler?,
+        passwords: any DocumentPasswordStoring,
+        folders: [URL],
+        modelsFolder: URL,
+        settings: UserDefaults = .standard,
+        settingsDomain: String?,
+        telemetry: Telemetry = ConsoleTelemetry()
+    ) {
+        self.store = store
+        self.backupScheduler = backupScheduler
+        self.passwords = passwords
+        self.folders = folders
+        self.modelsFolder = modelsFolder
+        self.settings = settings
+        self.settingsDomain = settingsDomain
+        self.telemetry = telemetry
+    }
+
+    public func execute(removingModels: Bool = false, removingSettings: Bool = false) async -> LibraryResetReport {
+        var failures: [LibraryResetFailure?] = [
+            await attempt(.documentPasswords) { try await passwords.removeAllPasswords() },
+            await attempt(.library) {
+                guard let store else {
+                    throw FilesMindError.notSupported("The library store cannot be erased")
+                }
+                try await store.eraseLibrary()
+            },
+            await attempt(.backups) { try await backupScheduler?.removeAllBackups() }
+        ]
+        for folder in folders {
+            failures.append(await attempt(.folder(folder)) { try Self.removeIfPresent(folder) })
+        }
+
+        var kept: [LibraryResetItem] = []
+        if removingModels {
+            failures.append(await attempt(.models(modelsFolder)) { try Self.removeIfPresent(modelsFolder) })
+        } else {
+            kept.append(.models(modelsFolder))
+        }
+        if removingSettings {
+            failures.append(await attempt(.settings) {
+                guard let settingsDomain else {
+                    throw FilesMindError.notSupported("This build has no bundle identifier for its settings")
+                }
+                settings.removePersistentDomain(forName: settingsDomain)
+            })
+        } else {
+            kept.append(.settings)
+        }
+
+        let report = LibraryResetReport(failures: failures.compactMap { $0 }, kept: kept)
+        telemetry.info("Library reset finished, items left behind: \(report.leftBehind.count)")
+        return report
+    }
+
+    private func attempt(_ item: LibraryResetItem, _ operation: () async throws -> Void) async -> LibraryResetFailure? {
+        do {
+            try await operation()
+            return nil
+        } catch {
+            telemetry.error("Library reset could not remove \(item): \(error.localizedDescription)")
+            return LibraryResetFailure(item: item, reason: error.localizedDescription)
+        }
+    }
+
+    private static func removeIfPresent(_ folder: URL) throws {
+        if FileManager.default.fileExists(atPath: folder.path) {
+            try FileManager.default.removeItem(at: folder)
+        }
+    }
+}
@@ -0,0 +1,155 @@
+import Domain
+import Foundation
+
+public enum SymlinkPolicy: String, Sendable, Equatable, Codable, CaseIterable {
+    case skip
+    case followWithinRoot
+    case followAnywhere
+}
+
+public struct WorkspaceWalkStats: Sendable, Equatable {
+    public var skippedSymlinks = 0
+    public var cyclesSkipped = 0
+    public var duplicateHardlinks = 0
+    public var ignoredEntries = 0
+    public var unreadableEntries = 0
+
+    public init() {}
+}
+
+/// Depth-first walk over the regular files below a root, guarding against directory cycles and hardlinked duplicates.
+public struct WorkspaceFileWalker: Sendable {
+    public static let resourceKeys: Set<URLResourceKey> = [
+        .isDirectoryKey,
+        .isRegularFileKey,
+        .isSymbolicLinkKey,
+        .isPackageKey,
+        .fileSizeKey,
+        .totalFileAllocatedSizeKey,
+        .isUbiquitousItemKey,
+        .ubiquitousItemDownloadingStatusKey
+    ]
+
+    private let symlinkPolicy: SymlinkPolicy
+    private let ignoreRules: WorkspaceIgnoreRules
+    private let skipsHiddenFiles: Bool
+
+    public init(
+        symlinkPolicy: SymlinkPolicy = .skip,
+        ignoreRules: WorkspaceIgnoreRules = WorkspaceIgnoreRules(contents: ""),
+        skipsHiddenFiles: Bool = true
+    ) {
+        self.symlinkPolicy = symlinkPolicy
+        self.ignoreRules = ignoreRules
+        self.skipsHiddenFiles = skipsHiddenFiles
+    }
+
+    /// Calls `visit` for each regular file in name order; returning false stops the walk.
+    @discardableResult
+    public func walk(_ rootURL: URL, visit: (URL, URLResourceValues) throws -> Bool) throws -> WorkspaceWalkStats {
+        let fileManager = FileManager.default
+        let root = rootURL.standardizedFileURL
+        let resolvedRootPath = root.resolvingSymlinksInPath().path
+        let options: FileManager.DirectoryEnumerationOptions = skipsHiddenFiles ? [.skipsHiddenFiles] : []
+
+        var stats = WorkspaceWalkStats()
+        var visitedDirectories: Set<FileIdentity> = []
+        var visitedFiles: Set<FileIdentity> = []
+        if let identity = FileIdentity(path: root.path) {
+            visitedDirectories.insert(identity)
+        }
+
+        var pending: [(url: URL, relativePath: String)] = [(root, "")]
+        while let next = pending.popLast() {
+            let directoryURL = next.url
+            let directoryPath = next.relativePath
+            let children: [URL]
+            do {
+                children = try fileManager.contentsOfDirectory(
+                    at: directoryURL,
+                    includingPropertiesForKeys: Array(Self.resourceKeys),
+                    options: options
+                )
+            } catch {
+                if directoryPath.isEmpty {
+                    throw FilesMindError.notFound("Unable to enumerate \(rootURL.path)")
+                }
+                stats.unreadableEntries += 1
+                continue
+            }
+
+            var subdirectories: [(url: URL, relativePath: String)] = []
+            for child in children.sorted(by: { $0.lastPathComponent < $1.lastPathComponent }) {
+                let relativePath = directoryPath.isEmpty
+                    ? child.lastPathComponent
+                    : "\(directoryPath)/\(child.lastPathComponent)"
+
+                guard var values = try? child.resourceValues(forKeys: Self.resourceKeys) else {
+                    stats.unreadableEntries += 1
+                    continue
+                }
+
+                var identityPath = child.path
+                if values.isSymbolicLink == true {
+                    let resolved = child.resolvingSymlinksInPath()
+                    let staysInRoot = resolved.path == resolvedRootPath || resolved.path.hasPrefix(resolvedRootPath + "/")
+                    guard symlinkPolicy == .followAnywhere || (symlinkPolicy == .followWithinRoot && staysInRoot) else {
+                        stats.skippedSymlinks += 1
+                        continue
+                    }
+                    guard let targetValues = try? resolved.resourceValues(forKeys: Self.resourceKeys) else {
+                        stats.unreadableEntries += 1
+                        continue
+                    }
+                    values = targetValues
+                    identityPath = resolved.path
+                }
+
+                let isDirectory = values.isDirectory == true
+                if ignoreRules.isIgnored(relativePath: relativePath, isDirectory: isDirectory) {
+                    stats.ignoredEntries += 1
+                    continue
+                }
+
+                if isDirectory {
+                    // Bundles such as .app or .rtfd are opaque documents, not folders to index.
+                    guard values.isPackage != true else { continue }
+                    if let identity = FileIdentity(path: identityPath), !visitedDirectories.insert(identity).inserted {
+                        stats.cyclesSkipped += 1
+                        continue
+                    }
+                    subdirectories.append((child, relativePath))
+                } else if values.isRegularFile == true {
+                    if let identity = FileIdentity(path: identityPath), !visitedFiles.insert(identity).inserted {
+                        stats.duplicateHardlinks += 1
+                        continue
+                    }
+                    guard try visit(child, values) else {
+                        return stats
+                    }
+                }
+            }
+
+            pending.append(contentsOf: subdirectories.reversed())
+        }
+
+        return stats
+    }
+}
+
+private struct FileIdentity: Hashable {
+    let device: UInt64
+    let inode: UInt64
+
+    init?(path: String) {
+        guard
+            let attributes = try? FileManager.default.attributesOfItem(atPath: path),
+            let device = (attributes[.systemNumber] as? NSNumber)?.uint64Value,
+            let inode = (attributes[.systemFileNumber] as? NSNumber)?.uint64Value
+        else {
+            return nil
+        }
+        self.device = device
+        self.inode = inode
+    }
+}
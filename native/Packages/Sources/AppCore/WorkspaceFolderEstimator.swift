@@ -0,0 +1,180 @@
+import DocumentPipeline
+import Domain
+import Foundation
+
+public enum WorkspaceFolderWarning: Sendable, Equatable {
+    case networkVolume
+    case cloudPlaceholders(count: Int)
+    case sampleLimitReached(visitedEntries: Int)
+    case unreadableEntries(count: Int)
+}
+
+public struct WorkspaceFolderEstimate: Sendable, Equatable {
+    public let directoryURL: URL
+    public let fileCount: Int
+    public let totalBytes: Int64
+    public let allocatedBytes: Int64
+    public let supportedFileCount: Int
+    public let supportedBytes: Int64
+    public let fileCountsByExtension: [String: Int]
+    public let projectedIndexBytes: Int64
+    public let projectedIndexDuration: TimeInterval
+    public let ignoredEntryCount: Int
+    public let warnings: [WorkspaceFolderWarning]
+
+    public init(
+        directoryURL: URL,
+        fileCount: Int,
+        totalBytes: Int64,
+        allocatedBytes: Int64? = nil,
+        supportedFileCount: Int,
+        supportedBytes: Int64,
+        fileCountsByExtension: [String: Int],
+        projectedIndexBytes: Int64,
+        projectedIndexDuration: TimeInterval,
+        ignoredEntryCount: Int = 0,
+        warnings: [WorkspaceFolderWarning]
+    ) {
+        self.directoryURL = directoryURL
+        self.fileCount = fileCount
+        self.totalBytes = totalBytes
+        self.allocatedBytes = allocatedBytes ?? totalBytes
+        self.supportedFileCount = supportedFileCount
+        self.supportedBytes = supportedBytes
+        self.fileCountsByExtension = fileCountsByExtension
+        self.projectedIndexBytes = projectedIndexBytes
+        self.projectedIndexDuration = projectedIndexDuration
+        self.ignoredEntryCount = ignoredEntryCount
+        self.warnings = warnings
+    }
+
+    /// Counts are lower bounds when the walk stopped early.
+    public var isPartial: Bool {
+        warnings.contains {
+            if case .sampleLimitReached = $0 { return true }
+            return false
+        }
+    }
+}
+
+public struct WorkspaceIndexProjection: Sendable, Equatable {
+    public let indexBytesPerTextByte: Double
+    public let indexBytesPerPDFByte: Double
+    public let textBytesPerSecond: Double
+    public let pdfBytesPerSecond: Double
+
+    public init(
+        indexBytesPerTextByte: Double = 1.6,
+        indexBytesPerPDFByte: Double = 0.2,
+        textBytesPerSecond: Double = 4_000_000,
+        pdfBytesPerSecond: Double = 1_500_000
+    ) {
+        self.indexBytesPerTextByte = indexBytesPerTextByte
+        self.indexBytesPerPDFByte = indexBytesPerPDFByte
+        self.textBytesPerSecond = max(1, textBytesPerSecond)
+        self.pdfBytesPerSecond = max(1, pdfBytesPerSecond)
+    }
+}
+
+public struct WorkspaceFolderEstimator: Sendable {
+    private let maxVisitedEntries: Int
+    private let timeBudget: TimeInterval
+    private let projection: WorkspaceIndexProjection
+    private let symlinkPolicy: SymlinkPolicy
+
+    public init(
+        maxVisitedEntries: Int = 20_000,
+        timeBudget: TimeInterval = 2,
+        projection: WorkspaceIndexProjection = WorkspaceIndexProjection(),
+        symlinkPolicy: SymlinkPolicy = .skip
+    ) {
+        self.maxVisitedEntries = max(1, maxVisitedEntries)
+        self.timeBudget = max(0.1, timeBudget)
+        self.projection = projection
+        self.symlinkPolicy = symlinkPolicy
+    }
+
+    public func estimate(directoryURL: URL) async throws -> WorkspaceFolderEstimate {
+        let walker = WorkspaceFileWalker(
+            symlinkPolicy: symlinkPolicy,
+            ignoreRules: WorkspaceIgnoreRules.load(in: directoryURL)
+        )
+        let deadline = Date().addingTimeInterval(timeBudget)
+        var visitedEntries = 0
+        var reachedLimit = false
+        var fileCount = 0
+        var totalBytes: Int64 = 0
+        var allocatedBytes: Int64 = 0
+        var textBytes: Int64 = 0
+        var pdfBytes: Int64 = 0
+        var supportedFileCount = 0
+        var placeholderCount = 0
+        var countsByExtension: [String: Int] = [:]
+
+        let stats = try walker.walk(directoryURL) { url, values in
+            visitedEntries += 1
+            if visitedEntries > maxVisitedEntries || (visitedEntries % 256 == 0 && Date() > deadline) {
+                reachedLimit = true
+                return false
+            }
+            if visitedEntries % 256 == 0 {
+                try Task.checkCancellation()
+            }
+
+            if values.isUbiquitousItem == true, values.ubiquitousItemDownloadingStatus != .current {
+                placeholderCount += 1
+            }
+
+            let ext = url.pathExtension.lowercased()
+            let bytes = Int64(values.fileSize ?? 0)
+            fileCount += 1
+            totalBytes += bytes
+            // Sparse and transparently compressed files occupy less than their logical size.
+            allocatedBytes += Int64(values.totalFileAllocatedSize ?? values.fileSize ?? 0)
+            countsByExtension[ext.isEmpty ? "(none)" : ext, default: 0] += 1
+
+            if DefaultDocumentParser.markdownFileExtensions.contains(ext) {
+                supportedFileCount += 1
+                textBytes += bytes
+            } else if DefaultDocumentParser.pdfFileExtensions.contains(ext) {
+                supportedFileCount += 1
+                pdfBytes += bytes
+            }
+            return true
+        }
+
+        var warnings: [WorkspaceFolderWarning] = []
+        let volumeValues = try? directoryURL.resourceValues(forKeys: [.volumeIsLocalKey])
+        if volumeValues?.volumeIsLocal == false {
+            warnings.append(.networkVolume)
+        }
+        if placeholderCount > 0 {
+            warnings.append(.cloudPlaceholders(count: placeholderCount))
+        }
+        if reachedLimit {
+            warnings.append(.sampleLimitReached(visitedEntries: visitedEntries - 1))
+        }
+        if stats.unreadableEntries > 0 {
+            warnings.append(.unreadableEntries(count: stats.unreadableEntries))
+        }
+
+        let projectedIndexBytes = Double(textBytes) * projection.indexBytesPerTextByte
+            + Double(pdfBytes) * projection.indexBytesPerPDFByte
+        let projectedDuration = Double(textBytes) / projection.textBytesPerSecond
+            + Double(pdfBytes) / projection.pdfBytesPerSecond
+
+        return WorkspaceFolderEstimate(
+            directoryURL: directoryURL,
+            fileCount: fileCount,
+            totalBytes: totalBytes,
+            allocatedBytes: allocatedBytes,
+            supportedFileCount: supportedFileCount,
+            supportedBytes: textBytes + pdfBytes,
+            fileCountsByExtension: countsByExtension,
+            projectedIndexBytes: Int64(projectedIndexBytes.rounded()),
+            projectedIndexDuration: projectedDuration,
+            ignoredEntryCount: stats.ignoredEntries,
+            warnings: warnings
+        )
+    }
+}
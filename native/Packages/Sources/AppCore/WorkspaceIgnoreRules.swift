@@ -0,0 +1,240 @@
+import Foundation
+
+/// Patterns from a workspace-root `.fmignore`, using gitignore syntax.
+public struct WorkspaceIgnoreRules: Sendable, Equatable {
+    public static let fileName = ".fmignore"
+
+    private let rules: [Rule]
+
+    public init(contents: String) {
+        self.rules = contents
+            .components(separatedBy: .newlines)
+            .compactMap(Rule.init(line:))
+    }
+
+    /// The file is re-read on every call so edits apply to the next scan without a restart.
+    public static func load(in directoryURL: URL) -> WorkspaceIgnoreRules {
+        let fileURL = directoryURL.appendingPathComponent(fileName, isDirectory: false)
+        guard let contents = try? String(contentsOf: fileURL, encoding: .utf8) else {
+            return WorkspaceIgnoreRules(contents: "")
+        }
+        return WorkspaceIgnoreRules(contents: contents)
+    }
+
+    public var isEmpty: Bool {
+        rules.isEmpty
+    }
+
+    /// `relativePath` is slash-separated from the workspace root. Like git, nothing below an ignored directory
+    /// can be re-included.
+    public func isIgnored(relativePath: String, isDirectory: Bool) -> Bool {
+        guard !rules.isEmpty else { return false }
+
+        let components = relativePath.split(separator: "/").map(String.init)
+        guard !components.isEmpty else { return false }
+
+        for depth in 1..<components.count {
+            if matches(components[0..<depth].joined(separator: "/"), isDirectory: true) {
+                return true
+            }
+        }
+        return matches(components.joined(separator: "/"), isDirectory: isDirectory)
+    }
+
+    public func isIgnored(_ url: URL, relativeTo rootURL: URL, isDirectory: Bool) -> Bool {
+        guard let relativePath = Self.relativePath(of: url, to: rootURL) else { return false }
+        return isIgnored(relativePath: relativePath, isDirectory: isDirectory)
+    }
+
+    static func relativePath(of url: URL, to rootURL: URL) -> String? {
+        let rootPath = rootURL.standardizedFileURL.path
+        let path = url.standardizedFileURL.path
+        let prefix = rootPath.hasSuffix("/") ? rootPath : rootPath + "/"
+        guard path.hasPrefix(prefix) else { return nil }
+        return String(path.dropFirst(prefix.count))
+    }
+
+    private func matches(_ path: String, isDirectory: Bool) -> Bool {
+        var ignored = false
+        let characters = Array(path)
+        for rule in rules where !rule.directoryOnly || isDirectory {
+            if rule.negated == ignored, rule.matches(characters) {
+                ignored = !rule.negated
+            }
+        }
+        return ignored
+    }
+}
+
+private struct Rule: Sendable, Equatable {
+    let tokens: [GlobToken]
+    let negated: Bool
+    let directoryOnly: Bool
+
+    init?(line: String) {
+        var pattern = line
+        while pattern.hasSuffix(" ") && !pattern.hasSuffix("\\ ") {
+            pattern.removeLast()
+        }
+        guard !pattern.isEmpty, !pattern.hasPrefix("#") else { return nil }
+
+        var negated = false
+        if pattern.hasPrefix("!") {
+            negated = true
+            pattern.removeFirst()
+        } else if pattern.hasPrefix("\\!") || pattern.hasPrefix("\\#") {
+            pattern.removeFirst()
+        }
+
+        var directoryOnly = false
+        if pattern.hasSuffix("/") {
+            directoryOnly = true
+            pattern.removeLast()
+        }
+
+        // A slash anywhere but the end anchors the pattern to the root; otherwise it matches at any depth.
+        let anchored = pattern.contains("/")
+        if pattern.hasPrefix("/") {
+            pattern.removeFirst()
+        }
+        guard !pattern.isEmpty else { return nil }
+
+        var tokens = GlobToken.tokenize(pattern)
+        if !anchored {
+            tokens.insert(.anyDirectories, at: 0)
+        }
+
+        self.tokens = tokens
+        self.negated = negated
+        self.directoryOnly = directoryOnly
+    }
+
+    func matches(_ path: [Character]) -> Bool {
+        GlobToken.match(tokens[...], path[...])
+    }
+}
+
+private enum GlobToken: Sendable, Equatable {
+    case literal(Character)
+    case anyCharacter
+    case anySegment
+    case anyPath
+    case anyDirectories
+    case characterClass(negated: Bool, ranges: [ClosedRange<Character>])
+
+    static func tokenize(_ pattern: String) -> [GlobToken] {
+        let characters = Array(pattern)
+        var tokens: [GlobToken] = []
+        var index = 0
+
+        while index < characters.count {
+            let character = characters[index]
+            switch character {
+            case "\\" where index + 1 < characters.count:
+                tokens.append(.literal(characters[index + 1]))
+                index += 2
+            case "*":
+                let isDouble = index + 1 < characters.count && characters[index + 1] == "*"
+                let startsSegment = index == 0 || characters[index - 1] == "/"
+                if isDouble && startsSegment {
+                    if index + 2 == characters.count {
+                        tokens.append(.anyPath)
+                        index += 2
+                    } else if characters[index + 2] == "/" {
+                        tokens.append(.anyDirectories)
+                        index += 3
+                    } else {
+                        tokens.append(.anySegment)
+                        index += 2
+                    }
+                } else {
+                    tokens.append(.anySegment)
+                    index += isDouble ? 2 : 1
+                }
+            case "?":
+                tokens.append(.anyCharacter)
+                index += 1
+            case "[":
+                if let (token, next) = parseClass(characters, openingAt: index) {
+                    tokens.append(token)
+                    index = next
+                } else {
+                    tokens.append(.literal(character))
+                    index += 1
+                }
+            default:
+                tokens.append(.literal(character))
+                index += 1
+            }
+        }
+        return tokens
+    }
+
+    private static func parseClass(_ characters: [Character], openingAt start: Int) -> (GlobToken, Int)? {
+        var index = start + 1
+        var negated = false
+        if index < characters.count, characters[index] == "!" || characters[index] == "^" {
+            negated = true
+            index += 1
+        }
+
+        var ranges: [ClosedRange<Character>] = []
+        var first = true
+        while index < characters.count {
+            let character = characters[index]
+            if character == "]" && !first {
+                return (.characterClass(negated: negated, ranges: ranges), index + 1)
+            }
+            first = false
+            if index + 2 < characters.count, characters[index + 1] == "-", characters[index + 2] != "]",
+               character <= characters[index + 2] {
+                ranges.append(character...characters[index + 2])
+                index += 3
+            } else {
+                ranges.append(character...character)
+                index += 1
+            }
+        }
+        return nil
+    }
+
+    static func match(_ tokens: ArraySlice<GlobToken>, _ path: ArraySlice<Character>) -> Bool {
+        guard let token = tokens.first else { return path.isEmpty }
+        let rest = tokens.dropFirst()
+
+        switch token {
+        case .literal(let expected):
+            guard let character = path.first, character == expected else { return false }
+            return match(rest, path.dropFirst())
+        case .anyCharacter:
+            guard let character = path.first, character != "/" else { return false }
+            return match(rest, path.dropFirst())
+        case .characterClass(let negated, let ranges):
+            guard let character = path.first, character != "/" else { return false }
+            guard ranges.contains(where: { $0.contains(character) }) != negated else { return false }
+            return match(rest, path.dropFirst())
+        case .anySegment:
+            var remaining = path
+            while true {
+                if match(rest, remaining) { return true }
+                guard let character = remaining.first, character != "/" else { return false }
+                remaining = remaining.dropFirst()
+            }
+        case .anyPath:
+            var remaining = path
+            while true {
+                if match(rest, remaining) { return true }
+                guard !remaining.isEmpty else { return false }
+                remaining = remaining.dropFirst()
+            }
+        case .anyDirectories:
+            if match(rest, path) { return true }
+            var remaining = path
+            while let slash = remaining.firstIndex(of: "/") {
+                remaining = remaining[remaining.index(after: slash)...]
+                if match(rest, remaining) { return true }
+            }
+            return false
+        }
+    }
+}
@@ -6,37 +6,46 @@ import PDFKit
 #endif
 
 public actor DefaultDocumentParser: DocumentParsing {
+    public static let markdownFileExtensions: Set<String> = ["md", "markdown", "txt"]
+    public static let pdfFileExtensions: Set<String> = ["pdf"]
+    public static var supportedFileExtensions: Set<String> {
+        markdownFileExtensions.union(pdfFileExtensions)
+    }
+
     private let router: PipelineRouter
+    private let passwordProvider: (any DocumentPasswordProviding)?
     private let telemetry: Telemetry
     private let maxChunkCharacters: Int
+    private let languageDetector = LanguageDetector()
 
     public init(
         router: PipelineRouter,
+        passwordProvider: (any DocumentPasswordProviding)? = nil,
         telemetry: Telemetry = ConsoleTelemetry(),
         maxChunkCharacters: Int = 1200
     ) {
         self.router = router
+        self.passwordProvider = passwordProvider
         self.telemetry = telemetry
         self.maxChunkCharacters = max(200, maxChunkCharacters)
     }
 
     public func parse(fileURL: URL) async throws -> ParsedDocument {
         let ext = fileURL.pathExtension.lowercased()
-        switch ext {
-        case "md", "markdown", "txt":
+        if Self.markdownFileExtensions.contains(ext) {
             return try parseMarkdown(fileURL: fileURL)
-        case "pdf":
+        }
+        if Self.pdfFileExtensions.contains(ext) {
             return try await parsePDF(fileURL: fileURL)
-        default:
-            throw FilesMindError.notSupported("Unsupported file type: .\(ext)")
         }
+        throw FilesMindError.notSupported("Unsupported file type: .\(ext)")
     }
 
     private func parseMarkdown(fileURL: URL) throws -> ParsedDocument {
         let raw = try readText(fileURL: fileURL)
         let normalized = normalize(raw)
 
-        let title = firstHeading(in: normalized) ?? fileURL.deletingPathExtension().lastPathComponent
+        let title = firstHeading(in: normalized) ?? SearchTextNormalizer.displayForm(fileURL.deletingPathExtension().lastPathComponent)
         let segments = normalized
             .components(separatedBy: "\n\n")
             .map { $0.trimmingCharacters(in: .whitespacesAndNewlines) }
@@ -82,7 +91,8 @@ public actor DefaultDocumentParser: DocumentParsing {
             sourceType: .markdown,
             chunks: chunks,
             sections: sections,
-            fallbackPageCount: 0
+            fallbackPageCount: 0,
+            languageCode: languageDetector.detectLanguage(in: normalized)
         )
     }
 
@@ -92,6 +102,15 @@ public actor DefaultDocumentParser: DocumentParsing {
             throw FilesMindError.validationFailed("Unable to open PDF")
         }
 
+        // Owner-password-only PDFs are encrypted but not locked, so their text is still readable.
+        if pdf.isLocked {
+            let password = try await passwordProvider?.password(for: fileURL)
+            guard let password, pdf.unlock(withPassword: password) else {
+                telemetry.warning("PDF is password protected: \(fileURL.lastPathComponent)")
+                throw FilesMindError.passwordRequired("Password required for \(fileURL.lastPathComponent)")
+            }
+        }
+
         var pageTexts: [(pageIndex: Int, text: String)] = []
         var assessments: [ParsePageAssessment] = []
 
@@ -149,18 +168,19 @@ public actor DefaultDocumentParser: DocumentParsing {
         return ParsedDocument(
             documentID: documentID,
             sourceURL: fileURL,
-            title: fileURL.deletingPathExtension().lastPathComponent,
+            title: SearchTextNormalizer.displayForm(fileURL.deletingPathExtension().lastPathComponent),
             sourceType: .pdf,
             chunks: chunks,
             sections: [
                 ParsedSection(
                     documentID: documentID,
                     level: 1,
-                    title: fileURL.deletingPathExtension().lastPathComponent,
+                    title: SearchTextNormalizer.displayForm(fileURL.deletingPathExtension().lastPathComponent),
                     chunkStartOrdinal: 0
                 )
             ],
-            lowQualityPages: lowQualityPages
+            lowQualityPages: lowQualityPages,
+            languageCode: languageDetector.detectLanguage(in: pageTexts.map(\.text).joined(separator: "\n"))
         )
 #else
         throw FilesMindError.notSupported("PDF parsing not available on this build")
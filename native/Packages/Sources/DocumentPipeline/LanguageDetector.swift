@@ -0,0 +1,35 @@
+import Foundation
+#if canImport(NaturalLanguage)
+import NaturalLanguage
+#endif
+
+public struct LanguageDetector: Sendable {
+    private let sampleCharacterLimit: Int
+    private let minimumConfidence: Double
+
+    public init(sampleCharacterLimit: Int = 4_000, minimumConfidence: Double = 0.5) {
+        self.sampleCharacterLimit = max(64, sampleCharacterLimit)
+        self.minimumConfidence = minimumConfidence
+    }
+
+    /// Returns a BCP 47 code such as "en" or "zh-Hans", or nil when the sample is too short or ambiguous.
+    public func detectLanguage(in text: String) -> String? {
+#if canImport(NaturalLanguage)
+        let sample = String(text.prefix(sampleCharacterLimit))
+        guard sample.contains(where: \.isLetter) else { return nil }
+
+        let recognizer = NLLanguageRecognizer()
+        recognizer.processString(sample)
+        guard
+            let (language, confidence) = recognizer.languageHypotheses(withMaximum: 1).first,
+            confidence >= minimumConfidence,
+            language != .undetermined
+        else {
+            return nil
+        }
+        return language.rawValue
+#else
+        return nil
+#endif
+    }
+}
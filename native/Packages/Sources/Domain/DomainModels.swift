@@ -6,6 +6,7 @@ public enum FilesMindError: Error, Sendable {
     case unauthorized(String)
     case validationFailed(String)
     case notSupported(String)
+    case passwordRequired(String)
 }
 
 public struct WorkspaceID: Hashable, Codable, Sendable {
@@ -62,6 +63,7 @@ public struct ModelDescriptor: Identifiable, Codable, Sendable, Equatable {
     public let remoteURL: URL
     public let sha256: String
     public let bytes: Int64
+    public let minimumMemoryBytes: Int64
 
     public init(
         modelID: String,
@@ -69,7 +71,8 @@ public struct ModelDescriptor: Identifiable, Codable, Sendable, Equatable {
         tier: ModelTier,
         remoteURL: URL,
         sha256: String,
-        bytes: Int64
+        bytes: Int64,
+        minimumMemoryBytes: Int64 = 0
     ) {
         self.modelID = modelID
         self.displayName = displayName
@@ -77,6 +80,134 @@ public struct ModelDescriptor: Identifiable, Codable, Sendable, Equatable {
         self.remoteURL = remoteURL
         self.sha256 = sha256
         self.bytes = bytes
+        self.minimumMemoryBytes = minimumMemoryBytes
+    }
+
+    // Catalogs written before the memory requirement existed omit it.
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        modelID = try container.decode(String.self, forKey: .modelID)
+        displayName = try container.decode(String.self, forKey: .displayName)
+        tier = try container.decode(ModelTier.self, forKey: .tier)
+        remoteURL = try container.decode(URL.self, forKey: .remoteURL)
+        sha256 = try container.decode(String.self, forKey: .sha256)
+        bytes = try container.decode(Int64.self, forKey: .bytes)
+        minimumMemoryBytes = try container.decodeIfPresent(Int64.self, forKey: .minimumMemoryBytes) ?? 0
+    }
+}
+
+public struct ModelDownloadProgress: Sendable, Equatable {
+    public let modelID: String
+    public let receivedBytes: Int64
+    public let totalBytes: Int64?
+    public let bytesPerSecond: Double
+
+    public init(modelID: String, receivedBytes: Int64, totalBytes: Int64?, bytesPerSecond: Double) {
+        self.modelID = modelID
+        self.receivedBytes = receivedBytes
+        self.totalBytes = totalBytes
+        self.bytesPerSecond = bytesPerSecond
+    }
+
+    public var fractionCompleted: Double? {
+        guard let totalBytes, totalBytes > 0 else { return nil }
+        return min(1, Double(receivedBytes) / Double(totalBytes))
+    }
+
+    public var estimatedTimeRemaining: TimeInterval? {
+        guard let totalBytes, bytesPerSecond > 0 else { return nil }
+        return Double(max(0, totalBytes - receivedBytes)) / bytesPerSecond
+    }
+}
+
+public struct InstalledModel: Identifiable, Sendable, Equatable {
+    public var id: String { modelID }
+
+    public let modelID: String
+    public let fileURL: URL
+    public let bytes: Int64
+
+    public init(modelID: String, fileURL: URL, bytes: Int64) {
+        self.modelID = modelID
+        self.fileURL = fileURL
+        self.bytes = bytes
+    }
+}
+
+public struct NetworkStatus: Sendable, Equatable {
+    public let isOnline: Bool
+    /// Cellular or a personal hotspot; large downloads should ask first.
+    public let isExpensive: Bool
+    /// The user turned on Low Data Mode.
+    public let isConstrained: Bool
+
+    public init(isOnline: Bool, isExpensive: Bool = false, isConstrained: Bool = false) {
+        self.isOnline = isOnline
+        self.isExpensive = isExpensive
+        self.isConstrained = isConstrained
+    }
+
+    public var isMetered: Bool {
+        isExpensive || isConstrained
+    }
+}
+
+public struct SystemProfile: Sendable, Equatable {
+    public let cpuModel: String
+    public let physicalCoreCount: Int
+    public let activeCoreCount: Int
+    public let physicalMemoryBytes: UInt64
+    public let availableMemoryBytes: UInt64?
+    public let availableDiskBytes: Int64?
+    public let osVersion: String
+
+    public init(
+        cpuModel: String,
+        physicalCoreCount: Int,
+        activeCoreCount: Int,
+        physicalMemoryBytes: UInt64,
+        availableMemoryBytes: UInt64?,
+        availableDiskBytes: Int64?,
+        osVersion: String
+    ) {
+        self.cpuModel = cpuModel
+        self.physicalCoreCount = physicalCoreCount
+        self.activeCoreCount = activeCoreCount
+        self.physicalMemoryBytes = physicalMemoryBytes
+        self.availableMemoryBytes = availableMemoryBytes
+        self.availableDiskBytes = availableDiskBytes
+        self.osVersion = osVersion
+    }
+}
+
+public enum ModelPreflightIssue: Sendable, Equatable {
+    case insufficientMemory(requiredBytes: Int64, physicalBytes: UInt64)
+    case insufficientDisk(requiredBytes: Int64, availableBytes: Int64)
+    case lowAvailableMemory(requiredBytes: Int64, availableBytes: UInt64)
+
+    public var isBlocking: Bool {
+        switch self {
+        case .insufficientMemory, .insufficientDisk:
+            return true
+        case .lowAvailableMemory:
+            return false
+        }
+    }
+}
+
+public struct ModelPreflightReport: Sendable, Equatable {
+    public let modelID: String
+    public let profile: SystemProfile
+    public let issues: [ModelPreflightIssue]
+
+    public init(modelID: String, profile: SystemProfile, issues: [ModelPreflightIssue]) {
+        self.modelID = modelID
+        self.profile = profile
+        self.issues = issues
+    }
+
+    public var meetsRequirements: Bool {
+        !issues.contains(where: \.isBlocking)
     }
 }
 
@@ -156,30 +287,80 @@ public enum ImportJobStatus: String, Sendable, Equatable, Codable {
     case parsing
     case indexed
     case failed
+    case passwordRequired
+}
+
+public enum ImportPriority: String, Sendable, Equatable, Codable, CaseIterable {
+    case userInitiated
+    case watchedFolder
+    case bulk
+
+    /// Lower rank is scheduled first.
+    public var rank: Int {
+        switch self {
+        case .userInitiated:
+            return 0
+        case .watchedFolder:
+            return 1
+        case .bulk:
+            return 2
+        }
+    }
 }
 
 public struct ImportJob: Identifiable, Sendable, Equatable, Codable {
     public let id: UUID
     public let fileURL: URL
     public let createdAt: Date
+    public var priority: ImportPriority
     public var status: ImportJobStatus
     public var progress: Double
     public var message: String?
+    public var attemptCount: Int
 
     public init(
         id: UUID = UUID(),
         fileURL: URL,
         createdAt: Date = Date(),
+        priority: ImportPriority = .userInitiated,
         status: ImportJobStatus,
         progress: Double,
-        message: String? = nil
+        message: String? = nil,
+        attemptCount: Int = 0
     ) {
         self.id = id
         self.fileURL = fileURL
         self.createdAt = createdAt
+        self.priority = priority
         self.status = status
         self.progress = progress
         self.message = message
+        self.attemptCount = attemptCount
+    }
+}
+
+public struct FailedImportRecord: Identifiable, Sendable, Equatable, Codable {
+    public let id: UUID
+    public let fileURL: URL
+    public let priority: ImportPriority
+    public let attemptCount: Int
+    public let lastError: String
+    public let failedAt: Date
+
+    public init(
+        id: UUID,
+        fileURL: URL,
+        priority: ImportPriority,
+        attemptCount: Int,
+        lastError: String,
+        failedAt: Date = Date()
+    ) {
+        self.id = id
+        self.fileURL = fileURL
+        self.priority = priority
+        self.attemptCount = attemptCount
+        self.lastError = lastError
+        self.failedAt = failedAt
     }
 }
 
@@ -219,6 +400,7 @@ public struct ParsedDocument: Sendable, Equatable {
     public let sections: [ParsedSection]
     public let lowQualityPages: [Int]
     public let fallbackPageCount: Int
+    public let languageCode: String?
 
     public init(
         documentID: UUID = UUID(),
@@ -228,7 +410,8 @@ public struct ParsedDocument: Sendable, Equatable {
         chunks: [Chunk],
         sections: [ParsedSection] = [],
         lowQualityPages: [Int] = [],
-        fallbackPageCount: Int? = nil
+        fallbackPageCount: Int? = nil,
+        languageCode: String? = nil
     ) {
         self.documentID = documentID
         self.sourceURL = sourceURL
@@ -238,6 +421,7 @@ public struct ParsedDocument: Sendable, Equatable {
         self.sections = sections
         self.lowQualityPages = lowQualityPages
         self.fallbackPageCount = fallbackPageCount ?? lowQualityPages.count
+        self.languageCode = languageCode
     }
 }
 
@@ -249,6 +433,7 @@ public struct ImportedDocumentRecord: Identifiable, Sendable, Equatable, Codable
     public let chunkCount: Int
     public let lowQualityPages: [Int]
     public let importedAt: Date
+    public let languageCode: String?
 
     public init(
         id: UUID,
@@ -257,7 +442,8 @@ public struct ImportedDocumentRecord: Identifiable, Sendable, Equatable, Codable
         sourceType: DocumentSourceType,
         chunkCount: Int,
         lowQualityPages: [Int],
-        importedAt: Date
+        importedAt: Date,
+        languageCode: String? = nil
     ) {
         self.id = id
         self.sourcePath = sourcePath
@@ -266,6 +452,7 @@ public struct ImportedDocumentRecord: Identifiable, Sendable, Equatable, Codable
         self.chunkCount = chunkCount
         self.lowQualityPages = lowQualityPages
         self.importedAt = importedAt
+        self.languageCode = languageCode
     }
 }
 
@@ -11,6 +11,7 @@ public protocol WorkspaceAuthorizationManaging: Sendable {
     func resolveAuthorization(id: WorkspaceID) async throws -> WorkspaceAuthorization
     func startScopedAccess(id: WorkspaceID) async throws -> WorkspaceAccessHandle
     func stopScopedAccess(_ handle: WorkspaceAccessHandle) async
+    func revokeAuthorization(id: WorkspaceID) async throws
 }
 
 public protocol ModelCatalogProviding: Sendable {
@@ -21,8 +22,48 @@ public protocol ModelArtifactValidating: Sendable {
     func validateArtifact(at url: URL, expectedSHA256: String) async throws
 }
 
+public protocol SystemProfiling: Sendable {
+    func currentProfile(volumeURL: URL) async throws -> SystemProfile
+}
+
+public protocol NetworkStatusProviding: Sendable {
+    func currentStatus() async -> NetworkStatus
+    /// Yields the current status first, then every change.
+    func statusUpdates() async -> AsyncStream<NetworkStatus>
+}
+
 public protocol ModelManaging: Sendable {
-    func install(modelID: String) async throws -> URL
+    func install(modelID: String, progress: (@Sendable (ModelDownloadProgress) -> Void)?) async throws -> URL
+    func installedModels() async throws -> [InstalledModel]
+    func preflight(modelID: String) async throws -> ModelPreflightReport
+}
+
+/// Model managers that can move later downloads onto another URLSession, e.g. after a proxy change.
+public protocol DownloadSessionReplaceable: Sendable {
+    /// A download already running finishes on the session it started with.
+    func replaceDownloadSession(_ session: URLSession) async
+}
+
+/// Model managers whose download bandwidth cap can change while the app runs.
+public protocol DownloadThrottling: Sendable {
+    /// Nil removes the cap.
+    func setMaxDownloadBytesPerSecond(_ limit: Int?) async
+}
+
+public extension ModelManaging {
+    func install(modelID: String) async throws -> URL {
+        try await install(modelID: modelID, progress: nil)
+    }
+}
+
+public protocol DocumentPasswordProviding: Sendable {
+    func password(for fileURL: URL) async throws -> String?
+}
+
+public protocol DocumentPasswordStoring: DocumentPasswordProviding {
+    func setPassword(_ password: String, for fileURL: URL, persist: Bool) async throws
+    func removePassword(for fileURL: URL) async throws
+    func removeAllPasswords() async throws
 }
 
 public protocol DocumentParsing: Sendable {
@@ -43,6 +84,29 @@ public protocol ImportedDocumentStore: Sendable {
     func sections(for documentID: UUID) async throws -> [ParsedSection]
 }
 
+public protocol DocumentRemoving: Sendable {
+    /// Drops the document record together with its sections and chunks.
+    func removeDocument(id: UUID) async throws
+}
+
+public protocol LibraryErasing: Sendable {
+    /// Deletes every document, section, chunk and failed import, leaving an empty store with the current schema.
+    func eraseLibrary() async throws
+}
+
+public protocol BackupCapableStore: Sendable {
+    /// Writes a consistent copy of the store while it stays open for reads and writes.
+    func writeBackup(to destinationURL: URL) async throws
+    /// Replaces the store's contents with a validated backup, keeping the current contents if that fails.
+    func restoreBackup(from backupURL: URL) async throws
+}
+
+public protocol FailedImportStore: Sendable {
+    func upsertFailedImport(_ record: FailedImportRecord) async throws
+    func failedImports() async throws -> [FailedImportRecord]
+    func removeFailedImport(id: UUID) async throws
+}
+
 public protocol ChunkRepository: Sendable {
     func upsert(_ chunks: [Chunk]) async throws
     func search(byKeyword keyword: String, limit: Int) async throws -> [Chunk]
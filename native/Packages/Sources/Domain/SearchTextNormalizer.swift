@@ -0,0 +1,19 @@
+import Foundation
+
+public enum SearchTextNormalizer {
+    private static let traditionalToSimplified = StringTransform("Hant-Hans")
+
+    /// Builds the comparison key used on both sides of a keyword match.
+    /// NFKC folds full-width Latin and digits to half-width and half-width katakana to full-width, composing
+    /// decomposed (NFD) filenames on the way. Traditional Chinese is then folded to Simplified so either script matches.
+    public static func searchKey(_ text: String) -> String {
+        let composed = text.precomposedStringWithCompatibilityMapping
+        let simplified = composed.applyingTransform(traditionalToSimplified, reverse: false) ?? composed
+        return simplified.lowercased()
+    }
+
+    /// Canonical composition only, for display strings such as titles derived from filenames.
+    public static func displayForm(_ text: String) -> String {
+        text.precomposedStringWithCanonicalMapping
+    }
+}
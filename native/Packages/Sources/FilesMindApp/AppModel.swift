@@ -4,7 +4,9 @@ import Domain
 import Foundation
 import GraphEngine
 import Observation
+import PDFKit
 import SearchKit
+import UniformTypeIdentifiers
 
 @MainActor
 @Observable
@@ -13,16 +15,20 @@ final class AppModel {
 
     var workspaceID = WorkspaceID("default-workspace")
     var workspaceURL: URL?
-    var workspaceStatus = "No workspace selected"
+    var workspaceStatus = String(localized: "No workspace selected", bundle: .module)
     var importJobs: [ImportJob] = []
+    var failedImports: [FailedImportRecord] = []
 
     var graphNodes: [GraphNode] = []
     var focusedGraphNodeID: UUID?
 
     var searchQuery = ""
     var searchResults: [RankedChunk] = []
-    var searchStatus = "Type keywords to search indexed chunks."
+    var searchStatus = String(localized: "Type keywords to search indexed chunks.", bundle: .module)
     var isSearching = false
+    var isExportingResults = false
+    /// Set from the Dock menu. Queued imports wait until the user resumes, including across a restore or reset.
+    private(set) var isIndexingPaused = false
     var activePageFilter: Int?
 
     var importedDocuments: [ImportedDocumentRecord] = []
@@ -34,18 +40,106 @@ final class AppModel {
     var reparseDiffScope: ReparseDiffScope = .remaining
 
     var lastError: String?
+    var networkStatus = NetworkStatus(isOnline: true)
+    var lastTrashedDocument: TrashedDocument?
+    var indexesInSpotlight = UserDefaults.standard.object(forKey: AppModel.spotlightDefaultsKey) as? Bool ?? true {
+        didSet {
+            UserDefaults.standard.set(indexesInSpotlight, forKey: Self.spotlightDefaultsKey)
+            if indexesInSpotlight {
+                Task { await reloadImportedDocuments() }
+            } else {
+                spotlight.removeAll()
+            }
+        }
+    }
+    var throttlesIndexingInLowPowerMode = UserDefaults.standard.bool(forKey: AppModel.lowPowerThrottleDefaultsKey) {
+        didSet {
+            UserDefaults.standard.set(throttlesIndexingInLowPowerMode, forKey: Self.lowPowerThrottleDefaultsKey)
+            applyImportConcurrencyCap()
+        }
+    }
+
+    /// Removing a document overwrites and deletes its file instead of moving it to the Trash.
+    var erasesRemovedDocumentsSecurely = UserDefaults.standard.bool(forKey: AppModel.secureEraseDefaultsKey) {
+        didSet {
+            UserDefaults.standard.set(erasesRemovedDocumentsSecurely, forKey: Self.secureEraseDefaultsKey)
+        }
+    }
+    /// System-wide shortcut for the quick-search panel; `.off` unregisters it.
+    var quickSearchShortcut = UserDefaults.standard.string(forKey: AppModel.quickSearchShortcutDefaultsKey)
+        .flatMap(QuickSearchShortcut.init(rawValue:)) ?? .optionSpace {
+        didSet {
+            UserDefaults.standard.set(quickSearchShortcut.rawValue, forKey: Self.quickSearchShortcutDefaultsKey)
+            applyQuickSearchShortcut()
+        }
+    }
+    /// How often the library is backed up and how many archives are kept.
+    private(set) var backupInterval = UserDefaults.standard.object(forKey: AppModel.backupIntervalDefaultsKey) as? TimeInterval
+        ?? 24 * 60 * 60
+    private(set) var backupRetainCount = UserDefaults.standard.object(forKey: AppModel.backupRetainCountDefaultsKey) as? Int ?? 7
+    /// A folder the user picked for backups; nil means the app's own Backups folder.
+    private(set) var customBackupDestination: URL?
+    var backupDestination: URL {
+        customBackupDestination ?? FilesMindPaths.backupsRootURL
+    }
+    /// `host:port` for model downloads; empty means they follow the system proxy settings.
+    private(set) var modelDownloadProxy = UserDefaults.standard.string(forKey: AppModel.modelDownloadProxyDefaultsKey) ?? ""
+    /// Bandwidth cap for model downloads in bytes per second; nil means unlimited.
+    private(set) var modelDownloadLimit = AppModel.savedDownloadLimit
+
+    static var savedProxyOverride: ProxyOverride? {
+        UserDefaults.standard.string(forKey: modelDownloadProxyDefaultsKey).flatMap(ProxyOverride.init(string:))
+    }
+
+    static var savedDownloadLimit: Int? {
+        UserDefaults.standard.object(forKey: modelDownloadLimitDefaultsKey) as? Int
+    }
+
+    private static let backupIntervalDefaultsKey = "backupInterval"
+    private static let backupRetainCountDefaultsKey = "backupRetainCount"
+    private static let customBackupDestinationDefaultsKey = "usesCustomBackupDestination"
+    private static let backupDestinationID = WorkspaceID("backup-destination")
+    private static let modelDownloadProxyDefaultsKey = "modelDownloadProxy"
+    private static let modelDownloadLimitDefaultsKey = "modelDownloadBytesPerSecond"
+    private static let lowPowerThrottleDefaultsKey = "throttlesIndexingInLowPowerMode"
+    private static let quickSearchShortcutDefaultsKey = "quickSearchShortcut"
+    private static let secureEraseDefaultsKey = "erasesRemovedDocumentsSecurely"
+    private static let spotlightDefaultsKey = "indexesInSpotlight"
 
     private let graphBoundary = Rect(x: -800, y: -800, width: 10000, height: 10000)
     private var graphIndex: QuadTreeIndex
     private var sectionNodeIDs: [UUID: UUID] = [:]
 
+    private let dockTileProgress = DockTileProgress()
+    private let thumbnails = DocumentThumbnailCache(directoryURL: FilesMindPaths.thumbnailsRootURL)
+    private let completionNotifier = ImportCompletionNotifier()
+    private let memoryPressure = MemoryPressureMonitor()
+    private let spotlight: SpotlightIndexer
+    private var memoryPressureImportCap: Int?
+    private var appliedImportCap: Int?
+    private var powerStateObserver: (any NSObjectProtocol)?
+    private var serviceProvider: FilesMindServiceProvider?
+    private var quickSearch: QuickSearchPanelController?
+    private var quickSearchHotKey: GlobalHotKey?
+    private var importBatch = ImportBatchTracker()
+    private var workspaceAccess: WorkspaceAccessHandle?
+    private var backupDestinationAccess: WorkspaceAccessHandle?
     private var queueObservationTask: Task<Void, Never>?
     private var reparseObservationTask: Task<Void, Never>?
+    private var networkObservationTask: Task<Void, Never>?
     private var started = false
+    private var isReady = false
+    private var pendingDeepLinks: [DeepLink] = []
     private var unfilteredSearchResults: [RankedChunk] = []
+    /// True while a restore or reset holds the import queue; resuming from the Dock waits for it to let go.
+    private var isLibraryMaintenanceRunning = false
+    /// What produced the list on screen, so an export matches it even after the field or filter changes.
+    private var searchedQuery = ""
+    private var appliedSearchPageFilter: Int?
 
     init(container: AppContainer) {
         self.container = container
+        self.spotlight = SpotlightIndexer(telemetry: container.telemetry)
         self.graphIndex = QuadTreeIndex(boundary: graphBoundary, capacity: 24)
 
         let placeholderNodes = DemoGraphFactory.makePlaceholderNodes()
@@ -111,6 +205,27 @@ final class AppModel {
     func start() {
         guard !started else { return }
         started = true
+        completionNotifier.activate()
+        let serviceProvider = FilesMindServiceProvider(model: self)
+        serviceProvider.register()
+        self.serviceProvider = serviceProvider
+        let quickSearch = QuickSearchPanelController(model: self)
+        self.quickSearch = quickSearch
+        quickSearchHotKey = GlobalHotKey { quickSearch.toggle() }
+        applyQuickSearchShortcut()
+        memoryPressure.start { [weak self] level in
+            self?.handleMemoryPressure(level)
+        }
+        powerStateObserver = NotificationCenter.default.addObserver(
+            forName: .NSProcessInfoPowerStateDidChange,
+            object: nil,
+            queue: .main
+        ) { [weak self] _ in
+            MainActor.assumeIsolated {
+                self?.applyImportConcurrencyCap()
+            }
+        }
+        applyImportConcurrencyCap()
 
         queueObservationTask = Task { [weak self] in
             guard let self else { return }
@@ -118,6 +233,11 @@ final class AppModel {
             let stream = await useCase.execute()
             for await jobs in stream {
                 self.importJobs = jobs
+                if let summary = self.importBatch.update(jobs: jobs) {
+                    self.completionNotifier.notify(summary)
+                }
+                self.dockTileProgress.update(jobs: jobs, batchProgress: self.importBatch.fractionCompleted)
+                await self.reloadFailedImports()
                 await self.reloadImportedDocuments()
                 self.synchronizeReparseInteractionState()
             }
@@ -133,9 +253,55 @@ final class AppModel {
             }
         }
 
+        networkObservationTask = Task { [weak self] in
+            guard let self else { return }
+            let stream = await self.container.networkStatus.statusUpdates()
+            for await status in stream {
+                self.networkStatus = status
+            }
+        }
+
         Task {
+            await self.restoreBackupDestination()
+            await self.applyBackupConfiguration()
+        }
+
+        Task {
+            await self.restoreWorkspace()
             await self.reloadImportedDocuments()
+            self.isReady = true
+            let queued = self.pendingDeepLinks
+            self.pendingDeepLinks = []
+            for link in queued {
+                self.perform(link)
+            }
+        }
+    }
+
+    func continueUserActivity(_ activity: NSUserActivity) {
+        guard let documentID = SpotlightIndexer.documentID(from: activity), let url = DeepLink.openDocument(documentID).url else {
+            return
+        }
+        handleOpenURL(url)
+    }
+
+    func handleOpenURL(_ url: URL) {
+        if url.isFileURL {
+            enqueueOpenedFiles([url])
+            return
+        }
+
+        guard let link = DeepLink(url: url) else {
+            lastError = String(localized: "Unsupported link: \(url.absoluteString)", bundle: .module)
+            return
         }
+
+        // Links can arrive before the document store has been read; replay them once it has.
+        guard isReady else {
+            pendingDeepLinks.append(link)
+            return
+        }
+        perform(link)
     }
 
     func chooseWorkspace() {
@@ -143,24 +309,37 @@ final class AppModel {
         panel.canChooseFiles = false
         panel.canChooseDirectories = true
         panel.allowsMultipleSelection = false
-        panel.prompt = "Select"
-        panel.message = "Choose your filesMind workspace folder"
+        panel.prompt = String(localized: "Select", bundle: .module)
+        panel.message = String(localized: "Choose your filesMind workspace folder", bundle: .module)
 
         guard panel.runModal() == .OK, let url = panel.url else {
             return
         }
 
-        workspaceStatus = "Authorizing \(url.lastPathComponent)..."
+        workspaceStatus = String(localized: "Scanning \(url.lastPathComponent)...", bundle: .module)
         lastError = nil
 
         Task {
             do {
+                let estimate = try await WorkspaceFolderEstimator().estimate(directoryURL: url)
+                guard confirmWorkspace(estimate) else {
+                    workspaceStatus = workspaceURL.map { String(localized: "Workspace: \($0.lastPathComponent)", bundle: .module) }
+                        ?? String(localized: "No workspace selected", bundle: .module)
+                    return
+                }
+
+                workspaceStatus = String(localized: "Authorizing \(url.lastPathComponent)...", bundle: .module)
                 let useCase = SelectWorkspaceUseCase(bookmarkManager: container.bookmarkManager)
                 let authorization = try await useCase.execute(workspaceID: workspaceID, directoryURL: url)
+                if let previous = workspaceAccess {
+                    await container.bookmarkManager.stopScopedAccess(previous)
+                    workspaceAccess = nil
+                }
+                workspaceAccess = try await container.bookmarkManager.startScopedAccess(id: workspaceID)
                 workspaceURL = authorization.directoryURL
-                workspaceStatus = "Workspace: \(authorization.directoryURL.lastPathComponent)"
+                workspaceStatus = String(localized: "Workspace: \(authorization.directoryURL.lastPathComponent)", bundle: .module)
             } catch {
-                workspaceStatus = "Workspace authorization failed"
+                workspaceStatus = String(localized: "Workspace authorization failed", bundle: .module)
                 lastError = error.localizedDescription
             }
         }
@@ -172,8 +351,8 @@ final class AppModel {
         panel.canChooseDirectories = false
         panel.allowsMultipleSelection = true
         panel.allowedContentTypes = []
-        panel.prompt = "Import"
-        panel.message = "Select Markdown or PDF files"
+        panel.prompt = String(localized: "Import", bundle: .module)
+        panel.message = String(localized: "Select Markdown or PDF files", bundle: .module)
 
         guard panel.runModal() == .OK else {
             return
@@ -188,6 +367,65 @@ final class AppModel {
         }
     }
 
+    func unlockImportJob(_ job: ImportJob) {
+        guard job.status == .passwordRequired else { return }
+
+        let passwordField = NSSecureTextField(frame: NSRect(x: 0, y: 28, width: 260, height: 24))
+        let rememberToggle = NSButton(
+            checkboxWithTitle: String(localized: "Remember in Keychain", bundle: .module),
+            target: nil,
+            action: nil
+        )
+        rememberToggle.frame = NSRect(x: 0, y: 0, width: 260, height: 20)
+        let accessory = NSView(frame: NSRect(x: 0, y: 0, width: 260, height: 52))
+        accessory.addSubview(passwordField)
+        accessory.addSubview(rememberToggle)
+
+        let alert = NSAlert()
+        alert.messageText = String(localized: "\(job.fileURL.lastPathComponent) is password protected", bundle: .module)
+        alert.informativeText = String(localized: "Enter the document password to continue importing.", bundle: .module)
+        alert.accessoryView = accessory
+        alert.addButton(withTitle: String(localized: "Unlock", bundle: .module))
+        alert.addButton(withTitle: String(localized: "Cancel", bundle: .module))
+        alert.window.initialFirstResponder = passwordField
+
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+        let password = passwordField.stringValue
+        let persist = rememberToggle.state == .on
+        guard !password.isEmpty else { return }
+
+        Task {
+            do {
+                try await container.documentPasswords.setPassword(password, for: job.fileURL, persist: persist)
+            } catch {
+                lastError = error.localizedDescription
+            }
+            await container.importQueue.resumeLockedJob(id: job.id)
+        }
+    }
+
+    func retryFailedImport(_ record: FailedImportRecord) {
+        Task {
+            do {
+                try await container.importQueue.retryFailedImport(id: record.id)
+            } catch {
+                lastError = error.localizedDescription
+            }
+            await reloadFailedImports()
+        }
+    }
+
+    func dismissFailedImport(_ record: FailedImportRecord) {
+        Task {
+            do {
+                try await container.importQueue.dismissFailedImport(id: record.id)
+            } catch {
+                lastError = error.localizedDescription
+            }
+            await reloadFailedImports()
+        }
+    }
+
     func visibleGraphNodes(in viewport: Rect) -> [GraphNode] {
         graphIndex.visibleNodes(in: viewport)
     }
@@ -204,16 +442,134 @@ final class AppModel {
         }
     }
 
+    func revealInFinder(_ document: ImportedDocumentRecord) {
+        guard let url = librarySourceURL(for: document) else { return }
+        NSWorkspace.shared.activateFileViewerSelecting([url])
+    }
+
+    func openWithDefaultApp(_ document: ImportedDocumentRecord) {
+        guard let url = librarySourceURL(for: document) else { return }
+        if !NSWorkspace.shared.open(url) {
+            lastError = String(localized: "No application is available to open \(url.lastPathComponent).", bundle: .module)
+        }
+    }
+
+    func moveToTrash(_ document: ImportedDocumentRecord) {
+        guard let url = librarySourceURL(for: document) else { return }
+        if erasesRemovedDocumentsSecurely {
+            erase(document, at: url)
+            return
+        }
+
+        let alert = NSAlert()
+        alert.messageText = String(localized: "Move \"\(document.title)\" to the Trash?", bundle: .module)
+        alert.informativeText = String(
+            localized: "The source file is moved to the Trash and the document is removed from the library.",
+            bundle: .module
+        )
+        alert.addButton(withTitle: String(localized: "Move to Trash", bundle: .module))
+        alert.addButton(withTitle: String(localized: "Cancel", bundle: .module))
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        var resultingURL: NSURL?
+        do {
+            try FileManager.default.trashItem(at: url, resultingItemURL: &resultingURL)
+        } catch {
+            lastError = error.localizedDescription
+            return
+        }
+        lastTrashedDocument = TrashedDocument(
+            title: document.title,
+            originalURL: url,
+            trashedURL: (resultingURL as URL?) ?? url
+        )
+
+        Task {
+            do {
+                try await (container.documentStore as? any DocumentRemoving)?.removeDocument(id: document.id)
+                spotlight.remove(documentID: document.id)
+            } catch {
+                lastError = error.localizedDescription
+            }
+            await forgetRemovedDocument(document.id)
+        }
+    }
+
+    /// The opt-in path for confidential documents. Nothing goes to the Trash, so there is no undo.
+    private func erase(_ document: ImportedDocumentRecord, at url: URL) {
+        let alert = NSAlert()
+        alert.alertStyle = .critical
+        alert.messageText = String(localized: "Permanently erase \"\(document.title)\"?", bundle: .module)
+        alert.informativeText = String(
+            localized: "The source file is overwritten and deleted, and its thumbnails, saved password and Spotlight entry are removed. This can't be undone. Library backups made before now still contain its text.",
+            bundle: .module
+        )
+        alert.addButton(withTitle: String(localized: "Erase", bundle: .module))
+        alert.addButton(withTitle: String(localized: "Cancel", bundle: .module))
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        thumbnails.removeThumbnails(for: url)
+        Task {
+            do {
+                try await EraseDocumentUseCase(
+                    documentRemover: container.documentStore as? any DocumentRemoving,
+                    passwords: container.documentPasswords,
+                    telemetry: container.telemetry
+                ).execute(documentID: document.id, sourceURL: url)
+            } catch {
+                lastError = String(localized: "Could not erase \(document.title): \(error.localizedDescription)", bundle: .module)
+                return
+            }
+            spotlight.remove(documentID: document.id)
+            await forgetRemovedDocument(document.id)
+        }
+    }
+
+    private func forgetRemovedDocument(_ documentID: UUID) async {
+        if selectedDocumentID == documentID {
+            selectedDocumentID = nil
+            selectedChunkPreview = nil
+        }
+        unfilteredSearchResults.removeAll { $0.chunk.documentID == documentID }
+        await reloadImportedDocuments()
+    }
+
+    /// Moves the last trashed file back and imports it again; the old document ID is not reused.
+    func restoreLastTrashedDocument() {
+        guard let item = lastTrashedDocument else { return }
+        lastTrashedDocument = nil
+
+        do {
+            try FileManager.default.moveItem(at: item.trashedURL, to: item.originalURL)
+        } catch {
+            lastError = String(localized: "Could not put back \(item.title): \(error.localizedDescription)", bundle: .module)
+            return
+        }
+        enqueueOpenedFiles([item.originalURL])
+    }
+
+    func showQuickSearch() {
+        quickSearch?.show()
+    }
+
+    private func applyQuickSearchShortcut() {
+        guard let quickSearchHotKey, !quickSearchHotKey.register(quickSearchShortcut) else { return }
+        lastError = String(
+            localized: "\(quickSearchShortcut.title) is already used by another app. Choose a different quick search shortcut in the Library menu.",
+            bundle: .module
+        )
+    }
+
     func runSearch() {
         let query = searchQuery.trimmingCharacters(in: .whitespacesAndNewlines)
         guard !query.isEmpty else {
             searchResults = []
-            searchStatus = "Type keywords to search indexed chunks."
+            searchStatus = String(localized: "Type keywords to search indexed chunks.", bundle: .module)
             return
         }
 
         isSearching = true
-        searchStatus = "Searching..."
+        searchStatus = String(localized: "Searching...", bundle: .module)
         lastError = nil
 
         Task {
@@ -226,17 +582,396 @@ final class AppModel {
                     vectorWeight: 0.0
                 )
                 unfilteredSearchResults = results
+                searchedQuery = query
                 applySearchPageFilter()
             } catch {
                 searchResults = []
                 unfilteredSearchResults = []
-                searchStatus = "Search failed."
+                searchStatus = String(localized: "Search failed.", bundle: .module)
                 lastError = error.localizedDescription
             }
             isSearching = false
         }
     }
 
+    private func handleMemoryPressure(_ level: MemoryPressureLevel) {
+        container.telemetry.warning("Memory pressure: \(level.rawValue)")
+        if level != .normal {
+            thumbnails.purgeMemory()
+        }
+
+        // Each running import holds a parsed document in memory, so run fewer until pressure eases.
+        switch level {
+        case .normal:
+            memoryPressureImportCap = nil
+        case .warning:
+            memoryPressureImportCap = 2
+        case .critical:
+            memoryPressureImportCap = 1
+        }
+        applyImportConcurrencyCap()
+    }
+
+    /// Memory pressure and the opt-in Low Power Mode policy both cap imports; the tighter one wins.
+    private func applyImportConcurrencyCap() {
+        let lowPowerCap = throttlesIndexingInLowPowerMode && ProcessInfo.processInfo.isLowPowerModeEnabled ? 1 : nil
+        let cap = [memoryPressureImportCap, lowPowerCap].compactMap { $0 }.min()
+        guard cap != appliedImportCap else { return }
+
+        appliedImportCap = cap
+        if lowPowerCap != nil {
+            container.telemetry.info("Low Power Mode on, indexing one document at a time")
+        }
+        Task {
+            await container.importQueue.setConcurrencyCap(cap)
+        }
+    }
+
+    /// Leaves the current proxy in place and returns false when `text` is neither empty nor a valid `host:port`.
+    @discardableResult
+    func setModelDownloadProxy(_ text: String) -> Bool {
+        let trimmed = text.trimmingCharacters(in: .whitespacesAndNewlines)
+        let proxyOverride = ProxyOverride(string: trimmed)
+        guard trimmed.isEmpty || proxyOverride != nil else { return false }
+
+        modelDownloadProxy = trimmed
+        if trimmed.isEmpty {
+            UserDefaults.standard.removeObject(forKey: Self.modelDownloadProxyDefaultsKey)
+        } else {
+            UserDefaults.standard.set(trimmed, forKey: Self.modelDownloadProxyDefaultsKey)
+        }
+        Task { await container.applyProxyOverride(proxyOverride) }
+        return true
+    }
+
+    func setModelDownloadLimit(_ bytesPerSecond: Int?) {
+        modelDownloadLimit = bytesPerSecond
+        if let bytesPerSecond {
+            UserDefaults.standard.set(bytesPerSecond, forKey: Self.modelDownloadLimitDefaultsKey)
+        } else {
+            UserDefaults.standard.removeObject(forKey: Self.modelDownloadLimitDefaultsKey)
+        }
+        Task { await container.applyDownloadLimit(bytesPerSecond: bytesPerSecond) }
+    }
+
+    func pauseIndexing() {
+        guard !isIndexingPaused else { return }
+        isIndexingPaused = true
+        Task { await container.importQueue.pause() }
+    }
+
+    func resumeIndexing() {
+        guard isIndexingPaused else { return }
+        isIndexingPaused = false
+        guard !isLibraryMaintenanceRunning else { return }
+        Task { await container.importQueue.resume() }
+    }
+
+    func setBackupSchedule(interval: TimeInterval, retainCount: Int) {
+        backupInterval = interval
+        backupRetainCount = retainCount
+        UserDefaults.standard.set(interval, forKey: Self.backupIntervalDefaultsKey)
+        UserDefaults.standard.set(retainCount, forKey: Self.backupRetainCountDefaultsKey)
+        Task { await applyBackupConfiguration() }
+    }
+
+    /// Archives already in the previous folder stay there; restore can still open them.
+    func chooseBackupDestination() {
+        let panel = NSOpenPanel()
+        panel.canChooseFiles = false
+        panel.canChooseDirectories = true
+        panel.canCreateDirectories = true
+        panel.allowsMultipleSelection = false
+        panel.directoryURL = backupDestination
+        panel.prompt = String(localized: "Use Folder", bundle: .module)
+        panel.message = String(localized: "Choose where library backups are saved", bundle: .module)
+        guard panel.runModal() == .OK, let url = panel.url else { return }
+
+        Task {
+            do {
+                _ = try await container.bookmarkManager.authorizeWorkspace(id: Self.backupDestinationID, directoryURL: url)
+                let handle = try await container.bookmarkManager.startScopedAccess(id: Self.backupDestinationID)
+                await replaceBackupDestinationAccess(with: handle)
+                UserDefaults.standard.set(true, forKey: Self.customBackupDestinationDefaultsKey)
+                await applyBackupConfiguration()
+            } catch {
+                lastError = String(localized: "Could not use \(url.lastPathComponent) for backups: \(error.localizedDescription)", bundle: .module)
+            }
+        }
+    }
+
+    func useDefaultBackupDestination() {
+        UserDefaults.standard.removeObject(forKey: Self.customBackupDestinationDefaultsKey)
+        Task {
+            try? await container.bookmarkManager.revokeAuthorization(id: Self.backupDestinationID)
+            await replaceBackupDestinationAccess(with: nil)
+            await applyBackupConfiguration()
+        }
+    }
+
+    private func restoreBackupDestination() async {
+        guard UserDefaults.standard.bool(forKey: Self.customBackupDestinationDefaultsKey) else { return }
+        do {
+            let handle = try await container.bookmarkManager.startScopedAccess(id: Self.backupDestinationID)
+            await replaceBackupDestinationAccess(with: handle)
+        } catch {
+            lastError = String(
+                localized: "The backup folder can't be reached, so backups go to the default folder until you choose it again.",
+                bundle: .module
+            )
+        }
+    }
+
+    private func replaceBackupDestinationAccess(with handle: WorkspaceAccessHandle?) async {
+        if let previous = backupDestinationAccess {
+            await container.bookmarkManager.stopScopedAccess(previous)
+        }
+        backupDestinationAccess = handle
+        customBackupDestination = handle?.directoryURL
+    }
+
+    private func applyBackupConfiguration() async {
+        await container.backupScheduler?.configure(
+            BackupConfiguration(interval: backupInterval, destinationURL: backupDestination, retainCount: backupRetainCount)
+        )
+    }
+
+    func backUpLibraryNow() {
+        guard let scheduler = container.backupScheduler else { return }
+        Task {
+            do {
+                let archiveURL = try await scheduler.backupNow()
+                NSWorkspace.shared.activateFileViewerSelecting([archiveURL])
+            } catch {
+                self.lastError = String(localized: "Backup failed: \(error.localizedDescription)", bundle: .module)
+            }
+        }
+    }
+
+    func restoreLibraryFromBackup() {
+        guard let scheduler = container.backupScheduler else { return }
+
+        let panel = NSOpenPanel()
+        panel.directoryURL = backupDestination
+        panel.allowedContentTypes = [UTType(filenameExtension: "sqlite") ?? .data]
+        panel.allowsMultipleSelection = false
+        panel.prompt = String(localized: "Restore", bundle: .module)
+        panel.message = String(localized: "Choose a library backup to restore", bundle: .module)
+        guard panel.runModal() == .OK, let backupURL = panel.url else {
+            return
+        }
+
+        let alert = NSAlert()
+        alert.messageText = String(localized: "Replace the library with \(backupURL.lastPathComponent)?", bundle: .module)
+        alert.informativeText = String(
+            localized: "Documents imported since this backup will no longer appear in FilesMind. The current library is kept as a pre-restore copy.",
+            bundle: .module
+        )
+        alert.addButton(withTitle: String(localized: "Restore", bundle: .module))
+        alert.addButton(withTitle: String(localized: "Cancel", bundle: .module))
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+
+        Task {
+            // Nothing may write to the library while its file is swapped; queued work picks up again afterwards.
+            isLibraryMaintenanceRunning = true
+            await container.importQueue.pause()
+            await container.lowQualityReparseQueue.pause()
+            do {
+                try await scheduler.restore(from: backupURL)
+                self.spotlight.removeAll()
+                self.searchResults = []
+                self.unfilteredSearchResults = []
+                self.selectedDocumentID = nil
+                self.selectedDocumentSections = []
+                self.selectedChunkPreview = nil
+                await self.reloadImportedDocuments()
+                await self.reloadFailedImports()
+            } catch {
+                self.lastError = String(localized: "Restore failed: \(error.localizedDescription)", bundle: .module)
+            }
+            await container.lowQualityReparseQueue.resume()
+            self.isLibraryMaintenanceRunning = false
+            if !self.isIndexingPaused {
+                await container.importQueue.resume()
+            }
+        }
+    }
+
+    /// Deletes everything FilesMind stored about the library and starts over empty. Source files stay where they are;
+    /// downloaded models and settings only go when the user ticks them.
+    func resetLibrary() {
+        let confirmationWord = String(localized: "RESET", bundle: .module)
+        let alert = NSAlert()
+        alert.alertStyle = .critical
+        alert.messageText = String(localized: "Reset the library?", bundle: .module)
+        alert.informativeText = String(
+            localized: "This deletes the index of every imported document, clipboard captures, all library backups, cached thumbnails, saved document passwords and Spotlight entries. Your original files are not touched. This can't be undone. Type \"\(confirmationWord)\" to confirm.",
+            bundle: .module
+        )
+        let field = NSTextField(frame: NSRect(x: 0, y: 0, width: 320, height: 24))
+        field.placeholderString = confirmationWord
+        let removesModels = NSButton(
+            checkboxWithTitle: String(localized: "Also delete downloaded models", bundle: .module),
+            target: nil,
+            action: nil
+        )
+        let removesSettings = NSButton(
+            checkboxWithTitle: String(localized: "Also reset settings and workspace access", bundle: .module),
+            target: nil,
+            action: nil
+        )
+        let accessory = NSStackView(views: [field, removesModels, removesSettings])
+        accessory.orientation = .vertical
+        accessory.alignment = .leading
+        accessory.frame = NSRect(x: 0, y: 0, width: 320, height: 76)
+        alert.accessoryView = accessory
+        alert.addButton(withTitle: String(localized: "Reset Library", bundle: .module))
+        alert.addButton(withTitle: String(localized: "Cancel", bundle: .module))
+        alert.window.initialFirstResponder = field
+        guard alert.runModal() == .alertFirstButtonReturn else { return }
+        guard field.stringValue.trimmingCharacters(in: .whitespaces) == confirmationWord else {
+            lastError = String(localized: "The library was not reset because the confirmation didn't match.", bundle: .module)
+            return
+        }
+        let removingModels = removesModels.state == .on
+        let removingSettings = removesSettings.state == .on
+
+        Task {
+            // Queued work would write old documents back into the emptied library, so it is dropped, not resumed.
+            isLibraryMaintenanceRunning = true
+            await container.importQueue.pause()
+            await container.lowQualityReparseQueue.pause()
+            await container.importQueue.clear()
+            await container.lowQualityReparseQueue.clear()
+            if removingSettings, let previous = workspaceAccess {
+                await container.bookmarkManager.stopScopedAccess(previous)
+                workspaceAccess = nil
+                workspaceURL = nil
+                workspaceStatus = String(localized: "No workspace selected", bundle: .module)
+            }
+            let report = await ResetLibraryUseCase(
+                store: container.chunkRepository as? any LibraryErasing,
+                backupScheduler: container.backupScheduler,
+                passwords: container.documentPasswords,
+                folders: [FilesMindPaths.capturesRootURL, FilesMindPaths.thumbnailsRootURL],
+                modelsFolder: FilesMindPaths.modelsRootURL,
+                settingsDomain: Bundle.main.bundleIdentifier,
+                telemetry: container.telemetry
+            ).execute(removingModels: removingModels, removingSettings: removingSettings)
+            self.thumbnails.purgeMemory()
+            self.spotlight.removeAll()
+            self.searchResults = []
+            self.unfilteredSearchResults = []
+            self.selectedDocumentID = nil
+            self.selectedDocumentSections = []
+            self.selectedChunkPreview = nil
+            await self.reloadImportedDocuments()
+            await self.reloadFailedImports()
+            self.searchStatus = String(localized: "The library was reset.", bundle: .module)
+            await container.lowQualityReparseQueue.resume()
+            self.isLibraryMaintenanceRunning = false
+            if !self.isIndexingPaused {
+                await container.importQueue.resume()
+            }
+            self.showResetReport(report)
+        }
+    }
+
+    private func showResetReport(_ report: LibraryResetReport) {
+        let lines = report.failures.map { failure in
+            String(localized: "Could not remove \(Self.title(for: failure.item)): \(failure.reason)", bundle: .module)
+        } + report.kept.map { item in
+            String(localized: "Kept: \(Self.title(for: item))", bundle: .module)
+        }
+        var paragraphs = [
+            lines.isEmpty
+                ? String(localized: "Nothing was left behind.", bundle: .module)
+                : ([String(localized: "Left behind:", bundle: .module)] + lines).joined(separator: "\n")
+        ]
+        if !report.leftBehind.contains(.settings) {
+            paragraphs.append(String(localized: "Settings return to their defaults the next time FilesMind opens.", bundle: .module))
+        }
+
+        let alert = NSAlert()
+        alert.messageText = String(localized: "The library was reset.", bundle: .module)
+        alert.informativeText = paragraphs.joined(separator: "\n\n")
+        alert.runModal()
+    }
+
+    private static func title(for item: LibraryResetItem) -> String {
+        switch item {
+        case .library:
+            return String(localized: "Library index", bundle: .module)
+        case .backups:
+            return String(localized: "Library backups", bundle: .module)
+        case .documentPasswords:
+            return String(localized: "Saved document passwords", bundle: .module)
+        case .folder(let url):
+            return url.path
+        case .models(let url):
+            return String(localized: "Downloaded models (\(url.path))", bundle: .module)
+        case .settings:
+            return String(localized: "Settings and workspace access", bundle: .module)
+        }
+    }
+
+    func exportSearchResults(format: SearchExportFormat) {
+        let query = searchedQuery
+        let pageFilter = appliedSearchPageFilter
+        guard !query.isEmpty, !searchResults.isEmpty, !isExportingResults else { return }
+
+        let panel = NSSavePanel()
+        panel.nameFieldStringValue = "search-results.\(format.fileExtension)"
+        panel.allowedContentTypes = [UTType(filenameExtension: format.fileExtension) ?? .plainText]
+        panel.prompt = String(localized: "Export", bundle: .module)
+        if let pageFilter {
+            panel.message = String(localized: "Export all results for \"\(query)\" on page \(pageFilter + 1)", bundle: .module)
+        } else {
+            panel.message = String(localized: "Export all results for \"\(query)\"", bundle: .module)
+        }
+
+        guard panel.runModal() == .OK, let destination = panel.url else {
+            return
+        }
+
+        isExportingResults = true
+        searchStatus = String(localized: "Exporting results...", bundle: .module)
+        lastError = nil
+        let documents = Dictionary(importedDocuments.map { ($0.id, $0) }, uniquingKeysWith: { first, _ in first })
+
+        Task {
+            do {
+                // The list only shows the top hits; exports re-run the same query without that cap and keep the page
+                // filter the list was showing.
+                var results = try await container.searchService.search(
+                    keyword: query,
+                    embedding: [],
+                    limit: 100_000,
+                    keywordWeight: 1.0,
+                    vectorWeight: 0.0
+                )
+                if let pageFilter {
+                    results.removeAll { $0.chunk.sourcePageIndex != pageFilter }
+                }
+                let written = try await SearchResultExporter().export(
+                    results,
+                    documents: documents,
+                    format: format,
+                    to: destination
+                ) { [weak self] progress in
+                    Task { @MainActor in
+                        self?.searchStatus = String(localized: "Exporting results... \(progress.fractionCompleted.formatted(.percent.precision(.fractionLength(0))))", bundle: .module)
+                    }
+                }
+                searchStatus = String(localized: "Exported \(written) results to \(destination.lastPathComponent).", bundle: .module)
+            } catch {
+                searchStatus = String(localized: "Export failed.", bundle: .module)
+                lastError = error.localizedDescription
+            }
+            isExportingResults = false
+        }
+    }
+
     func selectSearchResult(_ ranked: RankedChunk) {
         let chunk = ranked.chunk
         selectedChunkPreview = chunk.text
@@ -251,10 +986,10 @@ final class AppModel {
             applySearchPageFilter()
             if let matchedSection = bestSection(forChunkOrdinal: chunk.ordinal, in: selectedDocumentSections) {
                 focusedGraphNodeID = sectionNodeIDs[matchedSection.id]
-                searchStatus = "Focused \(matchedSection.title) (chunk #\(chunk.ordinal))."
+                searchStatus = String(localized: "Focused \(matchedSection.title) (chunk #\(chunk.ordinal)).", bundle: .module)
             } else {
                 focusedGraphNodeID = nil
-                searchStatus = "Focused chunk #\(chunk.ordinal), no section mapping."
+                searchStatus = String(localized: "Focused chunk #\(chunk.ordinal), no section mapping.", bundle: .module)
             }
         }
     }
@@ -265,9 +1000,9 @@ final class AppModel {
             let enqueued = await container.lowQualityReparseQueue.enqueue(document: doc)
             if enqueued {
                 let pageList = doc.lowQualityPages.map { String($0 + 1) }.joined(separator: ", ")
-                searchStatus = "Re-parse queued for pages [\(pageList)]."
+                searchStatus = String(localized: "Re-parse queued for pages [\(pageList)].", bundle: .module)
             } else {
-                searchStatus = "Re-parse already in progress for this document."
+                searchStatus = String(localized: "Re-parse already in progress for this document.", bundle: .module)
             }
         }
     }
@@ -293,6 +1028,33 @@ final class AppModel {
         applySearchPageFilter()
     }
 
+    /// Only paths recorded for documents in the library are handed to Finder, never arbitrary input.
+    private func librarySourceURL(for document: ImportedDocumentRecord) -> URL? {
+        guard let record = importedDocuments.first(where: { $0.id == document.id }) else {
+            lastError = String(localized: "\(document.title) is not in this library.", bundle: .module)
+            return nil
+        }
+
+        guard let url = Self.existingSourceURL(for: record) else {
+            lastError = String(localized: "\(record.title) is no longer at its original location.", bundle: .module)
+            return nil
+        }
+        return url
+    }
+
+    func thumbnail(for document: ImportedDocumentRecord, maxPixelSize: CGFloat) async -> NSImage? {
+        guard let url = Self.existingSourceURL(for: document) else { return nil }
+        return await thumbnails.thumbnail(for: url, maxPixelSize: maxPixelSize)
+    }
+
+    private static func existingSourceURL(for record: ImportedDocumentRecord) -> URL? {
+        // Rows imported before paths were stored decoded hold a percent-encoded `URL.path()`.
+        let candidates = [record.sourcePath, record.sourcePath.removingPercentEncoding].compactMap { $0 }
+        return candidates
+            .first(where: { FileManager.default.fileExists(atPath: $0) })
+            .map { URL(fileURLWithPath: $0) }
+    }
+
     private func reloadImportedDocuments() async {
         guard let store = container.documentStore else {
             importedDocuments = []
@@ -311,6 +1073,9 @@ final class AppModel {
                 sectionsMap[doc.id] = try await store.sections(for: doc.id)
             }
             rebuildGraph(documents: docs, sectionsMap: sectionsMap)
+            if indexesInSpotlight {
+                spotlight.index(docs, sections: sectionsMap)
+            }
 
             if let selectedDocumentID, docs.contains(where: { $0.id == selectedDocumentID }) {
                 selectedDocumentSections = sectionsMap[selectedDocumentID] ?? []
@@ -330,6 +1095,145 @@ final class AppModel {
         }
     }
 
+    private func restoreWorkspace() async {
+        do {
+            let useCase = RestoreWorkspaceUseCase(bookmarkManager: container.bookmarkManager)
+            guard let handle = try await useCase.execute(workspaceID: workspaceID) else { return }
+            workspaceAccess = handle
+            workspaceURL = handle.directoryURL
+            workspaceStatus = String(localized: "Workspace: \(handle.directoryURL.lastPathComponent)", bundle: .module)
+        } catch {
+            workspaceStatus = String(localized: "Workspace access needs to be granted again", bundle: .module)
+            lastError = error.localizedDescription
+        }
+    }
+
+    private func confirmWorkspace(_ estimate: WorkspaceFolderEstimate) -> Bool {
+        let formatter = ByteCountFormatter()
+        let supportedSize = formatter.string(fromByteCount: estimate.supportedBytes)
+        let indexDuration = Duration.seconds(estimate.projectedIndexDuration.rounded(.up))
+            .formatted(.units(allowed: [.hours, .minutes, .seconds], width: .abbreviated))
+        var lines = [
+            estimate.isPartial
+                ? String(localized: "At least \(estimate.supportedFileCount) Markdown/PDF files out of \(estimate.fileCount), \(supportedSize).", bundle: .module)
+                : String(localized: "\(estimate.supportedFileCount) Markdown/PDF files out of \(estimate.fileCount), \(supportedSize).", bundle: .module),
+            String(localized: "Projected index size: \(formatter.string(fromByteCount: estimate.projectedIndexBytes)).", bundle: .module),
+            String(localized: "Projected indexing time: \(indexDuration).", bundle: .module)
+        ]
+        if estimate.allocatedBytes < estimate.totalBytes * 9 / 10 {
+            let allocatedSize = formatter.string(fromByteCount: estimate.allocatedBytes)
+            let logicalSize = formatter.string(fromByteCount: estimate.totalBytes)
+            lines.append(String(localized: "Files take \(allocatedSize) on disk (\(logicalSize) logical) due to compression or sparse files.", bundle: .module))
+        }
+        if estimate.ignoredEntryCount > 0 {
+            lines.append(String(localized: "\(estimate.ignoredEntryCount) items excluded by \(WorkspaceIgnoreRules.fileName).", bundle: .module))
+        }
+
+        for warning in estimate.warnings {
+            switch warning {
+            case .networkVolume:
+                lines.append(String(localized: "This folder is on a network volume; indexing may be slow.", bundle: .module))
+            case .cloudPlaceholders(let count):
+                lines.append(String(localized: "\(count) files are cloud placeholders and will be downloaded when indexed.", bundle: .module))
+            case .sampleLimitReached(let visitedEntries):
+                lines.append(String(localized: "Scan stopped after \(visitedEntries) entries; totals are partial.", bundle: .module))
+            case .unreadableEntries(let count):
+                lines.append(String(localized: "\(count) items could not be read.", bundle: .module))
+            }
+        }
+
+        let alert = NSAlert()
+        alert.messageText = String(localized: "Use \(estimate.directoryURL.lastPathComponent) as workspace?", bundle: .module)
+        alert.informativeText = lines.joined(separator: "\n")
+        alert.addButton(withTitle: String(localized: "Use Folder", bundle: .module))
+        alert.addButton(withTitle: String(localized: "Cancel", bundle: .module))
+        return alert.runModal() == .alertFirstButtonReturn
+    }
+
+    func importDroppedItems(_ urls: [URL]) {
+        guard !urls.isEmpty else { return }
+        Task {
+            // Expanding a large folder touches every file, so keep it off the main actor.
+            let candidates = await Task.detached(priority: .userInitiated) {
+                ImportCandidateResolver().resolveExpandingDirectories(urls)
+            }.value
+            enqueue(candidates)
+        }
+    }
+
+    func captureClipboard() {
+        capture(from: .general)
+    }
+
+    /// Files import as if dropped; text becomes a Markdown note and images a one-page PDF so OCR can pick them up.
+    func capture(from pasteboard: NSPasteboard) {
+        if let urls = pasteboard.readObjects(forClasses: [NSURL.self], options: [.urlReadingFileURLsOnly: true]) as? [URL],
+           !urls.isEmpty {
+            importDroppedItems(urls)
+            return
+        }
+
+        let store = ClipboardCaptureStore(directoryURL: FilesMindPaths.capturesRootURL)
+        do {
+            let captureURL: URL
+            if let text = pasteboard.string(forType: .string), !text.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty {
+                captureURL = try store.writeText(text)
+            } else if let image = NSImage(pasteboard: pasteboard), let page = PDFPage(image: image) {
+                let document = PDFDocument()
+                document.insert(page, at: 0)
+                captureURL = try store.nextFileURL(pathExtension: "pdf")
+                guard document.write(to: captureURL) else {
+                    throw FilesMindError.invalidState("Unable to write \(captureURL.lastPathComponent)")
+                }
+            } else {
+                lastError = String(localized: "Nothing to capture: no text, image or files were provided.", bundle: .module)
+                return
+            }
+            enqueue(ImportCandidateResolver().resolve([captureURL]))
+        } catch {
+            lastError = String(localized: "Clipboard capture failed: \(error.localizedDescription)", bundle: .module)
+        }
+    }
+
+    private func enqueueOpenedFiles(_ urls: [URL]) {
+        enqueue(ImportCandidateResolver().resolve(urls))
+    }
+
+    private func enqueue(_ candidates: ImportCandidates) {
+        if !candidates.rejected.isEmpty {
+            let names = candidates.rejected.map(\.fileURL.lastPathComponent).joined(separator: ", ")
+            lastError = String(localized: "Skipped unsupported or unreadable files: \(names)", bundle: .module)
+        }
+        guard !candidates.accepted.isEmpty else { return }
+
+        Task {
+            let useCase = EnqueueImportUseCase(queue: container.importQueue)
+            await useCase.execute(fileURLs: candidates.accepted)
+        }
+    }
+
+    private func perform(_ link: DeepLink) {
+        switch link {
+        case .openDocument(let documentID):
+            guard let document = importedDocuments.first(where: { $0.id == documentID }) else {
+                searchStatus = String(localized: "Linked document is not in this library.", bundle: .module)
+                return
+            }
+            selectImportedDocument(document)
+        case .search(let query):
+            searchQuery = query
+            runSearch()
+        }
+    }
+
+    private func reloadFailedImports() async {
+        do {
+            failedImports = try await container.importQueue.failedImports()
+        } catch {
+            lastError = error.localizedDescription
+        }
+    }
+
     private func loadSections(for documentID: UUID) async {
         guard let store = container.documentStore else {
             selectedDocumentSections = []
@@ -366,14 +1270,15 @@ final class AppModel {
             filtered = unfilteredSearchResults
         }
         searchResults = filtered
+        appliedSearchPageFilter = activePageFilter
 
         let baseCount = unfilteredSearchResults.count
         if let page = activePageFilter {
-            searchStatus = "Filtered \(filtered.count)/\(baseCount) results for page \(page + 1)."
+            searchStatus = String(localized: "Filtered \(filtered.count)/\(baseCount) results for page \(page + 1).", bundle: .module)
         } else if baseCount > 0 {
-            searchStatus = "Found \(filtered.count) result(s)."
+            searchStatus = String(localized: "Found \(filtered.count) results.", bundle: .module)
         } else if !searchQuery.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty && !isSearching {
-            searchStatus = "No results."
+            searchStatus = String(localized: "No results.", bundle: .module)
         }
     }
 
@@ -406,7 +1311,9 @@ final class AppModel {
 
         for (docIndex, document) in documents.enumerated() {
             let baseY = Double(docIndex) * 300 + 80
-            let qualityBadge = document.lowQualityPages.isEmpty ? "Clean" : "LQ \(document.lowQualityPages.count)"
+            let qualityBadge = document.lowQualityPages.isEmpty
+                ? String(localized: "Clean", bundle: .module)
+                : String(localized: "LQ \(document.lowQualityPages.count)", bundle: .module)
 
             let rootNode = GraphNode(
                 title: "\(document.title) • \(qualityBadge)",
@@ -417,7 +1324,7 @@ final class AppModel {
             let sections = (sectionsMap[document.id] ?? []).sorted(by: { $0.chunkStartOrdinal < $1.chunkStartOrdinal })
             if sections.isEmpty {
                 let orphanNode = GraphNode(
-                    title: "(no outline)",
+                    title: String(localized: "(no outline)", bundle: .module),
                     rect: Rect(x: 380, y: baseY + 80, width: 180, height: 52)
                 )
                 newNodes.append(orphanNode)
@@ -468,9 +1375,15 @@ enum ReparseDiffScope: String, CaseIterable, Sendable {
 enum DemoGraphFactory {
     static func makePlaceholderNodes() -> [GraphNode] {
         [
-            GraphNode(title: "Import a document", rect: Rect(x: 120, y: 120, width: 260, height: 66)),
-            GraphNode(title: "Build local knowledge graph", rect: Rect(x: 430, y: 210, width: 280, height: 66)),
-            GraphNode(title: "Run semantic search", rect: Rect(x: 780, y: 300, width: 240, height: 66))
+            GraphNode(title: String(localized: "Import a document", bundle: .module), rect: Rect(x: 120, y: 120, width: 260, height: 66)),
+            GraphNode(title: String(localized: "Build local knowledge graph", bundle: .module), rect: Rect(x: 430, y: 210, width: 280, height: 66)),
+            GraphNode(title: String(localized: "Run semantic search", bundle: .module), rect: Rect(x: 780, y: 300, width: 240, height: 66))
         ]
     }
 }
+
+struct TrashedDocument: Equatable {
+    let title: String
+    let originalURL: URL
+    let trashedURL: URL
+}
@@ -0,0 +1,76 @@
+import DesignSystem
+import SwiftUI
+
+/// Settings pane for scheduled library backups: how often, how many to keep and where they go.
+struct BackupSettingsView: View {
+    private static let intervals: [TimeInterval] = [60 * 60, 6 * 60 * 60, 24 * 60 * 60, 7 * 24 * 60 * 60]
+
+    let model: AppModel
+
+    var body: some View {
+        Form {
+            Section {
+                Picker(
+                    String(localized: "Back up", bundle: .module),
+                    selection: Binding(
+                        get: { model.backupInterval },
+                        set: { model.setBackupSchedule(interval: $0, retainCount: model.backupRetainCount) }
+                    )
+                ) {
+                    ForEach(Self.intervals, id: \.self) { interval in
+                        Text(Self.title(forInterval: interval)).tag(interval)
+                    }
+                }
+
+                Stepper(
+                    String(localized: "Keep the newest \(model.backupRetainCount) backups", bundle: .module),
+                    value: Binding(
+                        get: { model.backupRetainCount },
+                        set: { model.setBackupSchedule(interval: model.backupInterval, retainCount: $0) }
+                    ),
+                    in: 1...60
+                )
+
+                LabeledContent(String(localized: "Folder", bundle: .module)) {
+                    Text(model.backupDestination.path)
+                        .lineLimit(1)
+                        .truncationMode(.middle)
+                        .textSelection(.enabled)
+                }
+
+                HStack {
+                    Spacer()
+                    Button(String(localized: "Use Default Folder", bundle: .module)) {
+                        model.useDefaultBackupDestination()
+                    }
+                    .disabled(model.customBackupDestination == nil)
+                    Button(String(localized: "Choose Folder...", bundle: .module)) {
+                        model.chooseBackupDestination()
+                    }
+                }
+            } header: {
+                Text("Library Backups", bundle: .module)
+            } footer: {
+                Text("Backups already in the previous folder stay there when you choose another one.", bundle: .module)
+                    .font(.system(size: DesignTypography.caption))
+                    .foregroundStyle(.secondary)
+            }
+        }
+        .formStyle(.grouped)
+        .frame(width: 480)
+        .disabled(model.container.backupScheduler == nil)
+    }
+
+    private static func title(forInterval interval: TimeInterval) -> String {
+        switch interval {
+        case 60 * 60:
+            return String(localized: "Every hour", bundle: .module)
+        case 6 * 60 * 60:
+            return String(localized: "Every 6 hours", bundle: .module)
+        case 7 * 24 * 60 * 60:
+            return String(localized: "Every week", bundle: .module)
+        default:
+            return String(localized: "Every day", bundle: .module)
+        }
+    }
+}
@@ -0,0 +1,67 @@
+import AppCore
+import AppKit
+import Domain
+
+/// Mirrors import queue progress on the Dock icon, plus a badge for documents indexed while the app was in the background.
+@MainActor
+final class DockTileProgress {
+    private let dockTile = NSApplication.shared.dockTile
+    // A content view replaces the icon entirely, so it needs the tile's size and draws the icon itself.
+    private lazy var progressView = DockTileProgressView(frame: NSRect(origin: .zero, size: dockTile.size))
+    private var indexedJobIDs: Set<UUID> = []
+    private var indexedWhileInactive = 0
+    private var activationObserver: NSObjectProtocol?
+
+    init() {
+        activationObserver = NotificationCenter.default.addObserver(
+            forName: NSApplication.didBecomeActiveNotification,
+            object: nil,
+            queue: .main
+        ) { [weak self] _ in
+            MainActor.assumeIsolated {
+                self?.clearBadge()
+            }
+        }
+    }
+
+    func update(jobs: [ImportJob], batchProgress: Double?) {
+        let indexedNow = Set(jobs.filter { $0.status == .indexed }.map(\.id))
+        let newlyIndexed = indexedNow.subtracting(indexedJobIDs).count
+        indexedJobIDs = indexedNow
+        if newlyIndexed > 0 && !NSApplication.shared.isActive {
+            indexedWhileInactive += newlyIndexed
+        }
+
+        progressView.fraction = batchProgress
+        progressView.needsDisplay = true
+        dockTile.contentView = batchProgress == nil ? nil : progressView
+        dockTile.badgeLabel = indexedWhileInactive > 0 ? String(indexedWhileInactive) : nil
+        dockTile.display()
+    }
+
+    private func clearBadge() {
+        indexedWhileInactive = 0
+        dockTile.badgeLabel = nil
+        dockTile.display()
+    }
+}
+
+private final class DockTileProgressView: NSView {
+    var fraction: Double?
+
+    override func draw(_ dirtyRect: NSRect) {
+        NSApplication.shared.applicationIconImage?.draw(in: bounds, from: .zero, operation: .sourceOver, fraction: 1)
+
+        guard let fraction else { return }
+        let track = NSRect(x: bounds.width * 0.1, y: bounds.height * 0.08, width: bounds.width * 0.8, height: bounds.height * 0.1)
+        let radius = track.height / 2
+
+        NSColor.black.withAlphaComponent(0.55).setFill()
+        NSBezierPath(roundedRect: track, xRadius: radius, yRadius: radius).fill()
+
+        var filled = track.insetBy(dx: 2, dy: 2)
+        filled.size.width *= min(max(fraction, 0), 1)
+        NSColor.controlAccentColor.setFill()
+        NSBezierPath(roundedRect: filled, xRadius: filled.height / 2, yRadius: filled.height / 2).fill()
+    }
+}
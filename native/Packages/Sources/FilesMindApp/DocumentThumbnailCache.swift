@@ -0,0 +1,79 @@
+import AppKit
+import CryptoKit
+import QuickLookThumbnailing
+
+/// QuickLook thumbnails persisted as PNGs, keyed by path, modification date and size so edits invalidate them.
+/// Each source file gets its own folder, so its thumbnails can be removed without knowing which sizes were drawn.
+@MainActor
+final class DocumentThumbnailCache {
+    private let directoryURL: URL
+    private var memoryCache: [String: NSImage] = [:]
+
+    init(directoryURL: URL) {
+        self.directoryURL = directoryURL
+    }
+
+    /// Drops decoded images; the PNGs on disk stay, so thumbnails come back cheaply.
+    func purgeMemory() {
+        memoryCache.removeAll()
+    }
+
+    func removeThumbnails(for fileURL: URL) {
+        let folderURL = folderURL(for: fileURL)
+        memoryCache = memoryCache.filter { !$0.key.hasPrefix(folderURL.lastPathComponent) }
+        try? FileManager.default.removeItem(at: folderURL)
+    }
+
+    func thumbnail(for fileURL: URL, maxPixelSize: CGFloat) async -> NSImage? {
+        guard let key = cacheKey(for: fileURL, maxPixelSize: maxPixelSize) else { return nil }
+        if let image = memoryCache[key] {
+            return image
+        }
+
+        let cachedURL = folderURL(for: fileURL).appendingPathComponent("\(key.dropFirst(key.count / 2)).png", isDirectory: false)
+        if let image = NSImage(contentsOf: cachedURL) {
+            memoryCache[key] = image
+            return image
+        }
+
+        let request = QLThumbnailGenerator.Request(
+            fileAt: fileURL,
+            size: CGSize(width: maxPixelSize, height: maxPixelSize),
+            scale: 1,
+            representationTypes: .thumbnail
+        )
+        guard let representation = try? await QLThumbnailGenerator.shared.generateBestRepresentation(for: request) else {
+            return nil
+        }
+
+        let image = representation.nsImage
+        memoryCache[key] = image
+        write(representation.cgImage, to: cachedURL)
+        return image
+    }
+
+    /// The folder hash followed by a hash of the version and size, so keys for one file share a prefix.
+    private func cacheKey(for fileURL: URL, maxPixelSize: CGFloat) -> String? {
+        guard let values = try? fileURL.resourceValues(forKeys: [.contentModificationDateKey, .fileSizeKey]) else {
+            return nil
+        }
+        let modified = values.contentModificationDate?.timeIntervalSince1970 ?? 0
+        let version = "\(modified)|\(values.fileSize ?? 0)|\(Int(maxPixelSize))"
+        return folderURL(for: fileURL).lastPathComponent + Self.hash(version)
+    }
+
+    private func folderURL(for fileURL: URL) -> URL {
+        directoryURL.appendingPathComponent(Self.hash(fileURL.standardizedFileURL.path), isDirectory: true)
+    }
+
+    private static func hash(_ value: String) -> String {
+        SHA256.hash(data: Data(value.utf8)).map { String(format: "%02x", $0) }.joined()
+    }
+
+    private func write(_ image: CGImage, to url: URL) {
+        let bitmap = NSBitmapImageRep(cgImage: image)
+        guard let data = bitmap.representation(using: .png, properties: [:]) else { return }
+        try? FileManager.default.createDirectory(at: url.deletingLastPathComponent(), withIntermediateDirectories: true)
+        try? data.write(to: url, options: .atomic)
+    }
+}
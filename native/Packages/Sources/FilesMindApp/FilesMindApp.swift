@@ -1,30 +1,51 @@
 import AppCore
+import CoreSpotlight
 import Foundation
 import SwiftUI
 
 @main
 struct FilesMindApp: App {
-    @State private var model = AppModel(container: AppBootstrap.makeDefault(installRoot: FilesMindPaths.modelsRootURL))
+    @NSApplicationDelegateAdaptor(FilesMindAppDelegate.self) private var appDelegate
+    @State private var model = AppModel(
+        container: AppBootstrap.makeDefault(
+            installRoot: FilesMindPaths.modelsRootURL,
+            proxyOverride: AppModel.savedProxyOverride,
+            maxDownloadBytesPerSecond: AppModel.savedDownloadLimit
+        )
+    )
 
     var body: some Scene {
         WindowGroup {
             WorkspaceShellView(model: model)
                 .frame(minWidth: 1180, minHeight: 760)
                 .onAppear {
+                    appDelegate.model = model
                     model.start()
                 }
+                .onOpenURL { url in
+                    model.handleOpenURL(url)
+                }
+                .onContinueUserActivity(CSSearchableItemActionType) { activity in
+                    model.continueUserActivity(activity)
+                }
         }
         .windowStyle(.titleBar)
         .defaultSize(width: 1320, height: 860)
-    }
-}
+        .commands {
+            FilesMindCommands(model: model)
+        }
 
-enum FilesMindPaths {
-    static var modelsRootURL: URL {
-        let base = FileManager.default.urls(for: .applicationSupportDirectory, in: .userDomainMask).first
-            ?? FileManager.default.temporaryDirectory
-        return base
-            .appendingPathComponent("FilesMind", isDirectory: true)
-            .appendingPathComponent("Models", isDirectory: true)
+        Settings {
+            TabView {
+                ModelDownloadSettingsView(model: model)
+                    .tabItem {
+                        Label(String(localized: "Downloads", bundle: .module), systemImage: "arrow.down.circle")
+                    }
+                BackupSettingsView(model: model)
+                    .tabItem {
+                        Label(String(localized: "Backups", bundle: .module), systemImage: "externaldrive")
+                    }
+            }
+        }
     }
 }
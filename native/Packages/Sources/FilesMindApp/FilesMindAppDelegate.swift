@@ -0,0 +1,77 @@
+import AppKit
+import Domain
+
+/// Supplies the Dock menu. It's built when the menu opens, so it always matches the library's recent documents.
+@MainActor
+final class FilesMindAppDelegate: NSObject, NSApplicationDelegate {
+    static let recentLimit = 10
+
+    weak var model: AppModel?
+
+    func applicationDockMenu(_ sender: NSApplication) -> NSMenu? {
+        guard let model else { return nil }
+
+        let menu = NSMenu()
+        let recent = model.importedDocuments.prefix(Self.recentLimit)
+        for document in recent {
+            let item = NSMenuItem(title: document.title, action: #selector(openRecentDocument(_:)), keyEquivalent: "")
+            item.target = self
+            item.representedObject = document.id
+            menu.addItem(item)
+        }
+        if !recent.isEmpty {
+            menu.addItem(.separator())
+        }
+
+        let capture = NSMenuItem(title: String(localized: "Capture Clipboard", bundle: .module), action: #selector(captureClipboard(_:)), keyEquivalent: "")
+        capture.target = self
+        menu.addItem(capture)
+
+        let importFiles = NSMenuItem(title: String(localized: "Import Files...", bundle: .module), action: #selector(importFiles(_:)), keyEquivalent: "")
+        importFiles.target = self
+        menu.addItem(importFiles)
+
+        let indexingTitle = model.isIndexingPaused
+            ? String(localized: "Resume Indexing", bundle: .module)
+            : String(localized: "Pause Indexing", bundle: .module)
+        let indexing = NSMenuItem(title: indexingTitle, action: #selector(toggleIndexing(_:)), keyEquivalent: "")
+        indexing.target = self
+        menu.addItem(indexing)
+        return menu
+    }
+
+    @objc private func openRecentDocument(_ sender: NSMenuItem) {
+        guard
+            let model,
+            let documentID = sender.representedObject as? UUID,
+            let document = model.importedDocuments.first(where: { $0.id == documentID })
+        else {
+            return
+        }
+        bringToFront()
+        model.selectImportedDocument(document)
+    }
+
+    @objc private func captureClipboard(_ sender: NSMenuItem) {
+        model?.captureClipboard()
+    }
+
+    @objc private func importFiles(_ sender: NSMenuItem) {
+        bringToFront()
+        model?.chooseAndEnqueueImports()
+    }
+
+    @objc private func toggleIndexing(_ sender: NSMenuItem) {
+        guard let model else { return }
+        if model.isIndexingPaused {
+            model.resumeIndexing()
+        } else {
+            model.pauseIndexing()
+        }
+    }
+
+    private func bringToFront() {
+        NSApplication.shared.activate()
+        NSApplication.shared.windows.first(where: \.canBecomeMain)?.makeKeyAndOrderFront(nil)
+    }
+}
@@ -0,0 +1,140 @@
+import Domain
+import SwiftUI
+
+struct FilesMindCommands: Commands {
+    let model: AppModel
+
+    var body: some Commands {
+        CommandGroup(replacing: .newItem) {
+            Button(String(localized: "Import Files...", bundle: .module)) {
+                model.chooseAndEnqueueImports()
+            }
+            .keyboardShortcut("o", modifiers: .command)
+
+            Button(String(localized: "Choose Workspace...", bundle: .module)) {
+                model.chooseWorkspace()
+            }
+            .keyboardShortcut("o", modifiers: [.command, .shift])
+
+            Button(String(localized: "Capture Clipboard", bundle: .module)) {
+                model.captureClipboard()
+            }
+            .keyboardShortcut("v", modifiers: [.command, .shift])
+
+            // Recent documents come from the library itself, so this list and the Dock menu follow imports
+            // without a separate store.
+            Menu(String(localized: "Open Recent", bundle: .module)) {
+                let recent = Array(model.importedDocuments.prefix(FilesMindAppDelegate.recentLimit))
+                if recent.isEmpty {
+                    Text("No Recent Documents", bundle: .module)
+                } else {
+                    ForEach(recent) { document in
+                        Button(document.title) {
+                            model.selectImportedDocument(document)
+                        }
+                    }
+                }
+            }
+        }
+
+        CommandGroup(after: .importExport) {
+            Menu(String(localized: "Export Search Results", bundle: .module)) {
+                Button(String(localized: "CSV...", bundle: .module)) {
+                    model.exportSearchResults(format: .csv)
+                }
+                Button(String(localized: "JSON Lines...", bundle: .module)) {
+                    model.exportSearchResults(format: .jsonl)
+                }
+            }
+            .disabled(model.searchResults.isEmpty || model.isExportingResults)
+        }
+
+        CommandMenu(Text("Library", bundle: .module)) {
+            Button(String(localized: "Run Search", bundle: .module)) {
+                model.runSearch()
+            }
+            .keyboardShortcut(.return, modifiers: .command)
+            .disabled(model.isSearching)
+
+            Button(String(localized: "Quick Search", bundle: .module)) {
+                model.showQuickSearch()
+            }
+
+            Picker(
+                String(localized: "Quick Search Shortcut", bundle: .module),
+                selection: Binding(
+                    get: { model.quickSearchShortcut },
+                    set: { model.quickSearchShortcut = $0 }
+                )
+            ) {
+                ForEach(QuickSearchShortcut.allCases, id: \.self) { shortcut in
+                    Text(shortcut.title).tag(shortcut)
+                }
+            }
+
+            Button(String(localized: "Show in Finder", bundle: .module)) {
+                if let document = model.selectedDocument {
+                    model.revealInFinder(document)
+                }
+            }
+            .keyboardShortcut("r", modifiers: [.command, .option])
+            .disabled(model.selectedDocument == nil)
+
+            Button(String(localized: "Open with Default App", bundle: .module)) {
+                if let document = model.selectedDocument {
+                    model.openWithDefaultApp(document)
+                }
+            }
+            .disabled(model.selectedDocument == nil)
+
+            Divider()
+
+            Button(String(localized: "Re-parse Low-Quality Pages", bundle: .module)) {
+                model.requestReparseLowQualityPages()
+            }
+            .disabled(model.selectedDocument?.lowQualityPages.isEmpty ?? true)
+
+            Toggle(
+                String(localized: "Show Documents in Spotlight", bundle: .module),
+                isOn: Binding(
+                    get: { model.indexesInSpotlight },
+                    set: { model.indexesInSpotlight = $0 }
+                )
+            )
+
+            Toggle(
+                String(localized: "Slow Indexing in Low Power Mode", bundle: .module),
+                isOn: Binding(
+                    get: { model.throttlesIndexingInLowPowerMode },
+                    set: { model.throttlesIndexingInLowPowerMode = $0 }
+                )
+            )
+
+            Toggle(
+                String(localized: "Erase Removed Documents Securely", bundle: .module),
+                isOn: Binding(
+                    get: { model.erasesRemovedDocumentsSecurely },
+                    set: { model.erasesRemovedDocumentsSecurely = $0 }
+                )
+            )
+
+            Divider()
+
+            Button(String(localized: "Back Up Library Now", bundle: .module)) {
+                model.backUpLibraryNow()
+            }
+            .disabled(model.container.backupScheduler == nil)
+
+            Button(String(localized: "Restore Library from Backup...", bundle: .module)) {
+                model.restoreLibraryFromBackup()
+            }
+            .disabled(model.container.backupScheduler == nil)
+
+            Divider()
+
+            Button(String(localized: "Reset Library...", bundle: .module)) {
+                model.resetLibrary()
+            }
+        }
+    }
+}
@@ -0,0 +1,26 @@
+import AppKit
+
+/// Backs the "Add to FilesMind" entry in the Services menu and Finder's Quick Actions.
+/// The entry itself is declared under NSServices in the app's Info.plist.
+@MainActor
+final class FilesMindServiceProvider: NSObject {
+    private let model: AppModel
+
+    init(model: AppModel) {
+        self.model = model
+    }
+
+    func register() {
+        NSApplication.shared.servicesProvider = self
+        NSUpdateDynamicServices()
+    }
+
+    @objc func addToFilesMind(
+        _ pasteboard: NSPasteboard,
+        userData: String?,
+        error: AutoreleasingUnsafeMutablePointer<NSString?>
+    ) {
+        model.capture(from: pasteboard)
+        NSApplication.shared.activate()
+    }
+}
@@ -0,0 +1,115 @@
+import Carbon.HIToolbox
+
+/// Shortcuts offered for opening the quick-search panel from any app.
+enum QuickSearchShortcut: String, CaseIterable, Sendable {
+    case off
+    case optionSpace
+    case controlOptionSpace
+    case shiftCommandSpace
+    case optionCommandF
+
+    var title: String {
+        switch self {
+        case .off:
+            return String(localized: "Off", bundle: .module)
+        case .optionSpace:
+            return String(localized: "⌥Space", bundle: .module)
+        case .controlOptionSpace:
+            return String(localized: "⌃⌥Space", bundle: .module)
+        case .shiftCommandSpace:
+            return String(localized: "⇧⌘Space", bundle: .module)
+        case .optionCommandF:
+            return String(localized: "⌥⌘F", bundle: .module)
+        }
+    }
+
+    fileprivate var keyCode: UInt32? {
+        switch self {
+        case .off:
+            return nil
+        case .optionSpace, .controlOptionSpace, .shiftCommandSpace:
+            return UInt32(kVK_Space)
+        case .optionCommandF:
+            return UInt32(kVK_ANSI_F)
+        }
+    }
+
+    fileprivate var modifiers: UInt32 {
+        switch self {
+        case .off:
+            return 0
+        case .optionSpace:
+            return UInt32(optionKey)
+        case .controlOptionSpace:
+            return UInt32(controlKey | optionKey)
+        case .shiftCommandSpace:
+            return UInt32(shiftKey | cmdKey)
+        case .optionCommandF:
+            return UInt32(optionKey | cmdKey)
+        }
+    }
+}
+
+/// A system-wide shortcut registered with the Carbon event manager. It is still the only public API that sees a key
+/// press while another app is frontmost without asking for Accessibility or Input Monitoring access.
+@MainActor
+final class GlobalHotKey {
+    private static let signature: OSType = 0x464D_4E44 // "FMND"
+
+    private let action: @MainActor () -> Void
+    private var hotKeyRef: EventHotKeyRef?
+    private var handlerRef: EventHandlerRef?
+
+    init(action: @escaping @MainActor () -> Void) {
+        self.action = action
+    }
+
+    /// Replaces the current registration. Returns false when another app already holds the combination.
+    func register(_ shortcut: QuickSearchShortcut) -> Bool {
+        unregister()
+        guard let keyCode = shortcut.keyCode else { return true }
+        installHandlerIfNeeded()
+
+        var ref: EventHotKeyRef?
+        let status = RegisterEventHotKey(
+            keyCode,
+            shortcut.modifiers,
+            EventHotKeyID(signature: Self.signature, id: 1),
+            GetApplicationEventTarget(),
+            0,
+            &ref
+        )
+        guard status == noErr, let ref else { return false }
+        hotKeyRef = ref
+        return true
+    }
+
+    func unregister() {
+        if let hotKeyRef {
+            UnregisterEventHotKey(hotKeyRef)
+        }
+        hotKeyRef = nil
+    }
+
+    private func installHandlerIfNeeded() {
+        guard handlerRef == nil else { return }
+        var eventType = EventTypeSpec(eventClass: OSType(kEventClassKeyboard), eventKind: UInt32(kEventHotKeyPressed))
+        // The hot key lives as long as the app, so the unretained pointer stays valid. Carbon delivers application
+        // target events on the main thread.
+        InstallEventHandler(
+            GetApplicationEventTarget(),
+            { _, _, userData in
+                guard let userData else { return OSStatus(eventNotHandledErr) }
+                let hotKey = Unmanaged<GlobalHotKey>.fromOpaque(userData).takeUnretainedValue()
+                MainActor.assumeIsolated {
+                    hotKey.action()
+                }
+                return noErr
+            },
+            1,
+            &eventType,
+            Unmanaged.passUnretained(self).toOpaque(),
+            &handlerRef
+        )
+    }
+}
@@ -0,0 +1,61 @@
+import AppCore
+import AppKit
+import UserNotifications
+
+/// Posts a system notification when an import batch finishes while FilesMind is in the background.
+@MainActor
+final class ImportCompletionNotifier: NSObject {
+    private var authorizationRequested = false
+
+    // UNUserNotificationCenter traps in processes without a bundle, e.g. `swift run`.
+    private var center: UNUserNotificationCenter? {
+        Bundle.main.bundleIdentifier == nil ? nil : UNUserNotificationCenter.current()
+    }
+
+    func activate() {
+        center?.delegate = self
+    }
+
+    func notify(_ summary: ImportBatchSummary) {
+        guard let center, !NSApplication.shared.isActive, summary.totalCount > 0 else { return }
+
+        let content = UNMutableNotificationContent()
+        content.title = String(localized: "Import finished", bundle: .module)
+        content.body = Self.body(for: summary)
+        content.sound = .default
+
+        let request = UNNotificationRequest(identifier: UUID().uuidString, content: content, trigger: nil)
+        let needsAuthorization = !authorizationRequested
+        authorizationRequested = true
+
+        Task {
+            if needsAuthorization {
+                _ = try? await center.requestAuthorization(options: [.alert, .sound])
+            }
+            try? await center.add(request)
+        }
+    }
+
+    private static func body(for summary: ImportBatchSummary) -> String {
+        var parts = [String(localized: "Indexed \(summary.indexedCount) documents.", bundle: .module)]
+        if summary.failedCount > 0 {
+            parts.append(String(localized: "\(summary.failedCount) failed.", bundle: .module))
+        }
+        if summary.lockedCount > 0 {
+            parts.append(String(localized: "\(summary.lockedCount) need a password.", bundle: .module))
+        }
+        return parts.joined(separator: " ")
+    }
+}
+
+extension ImportCompletionNotifier: UNUserNotificationCenterDelegate {
+    nonisolated func userNotificationCenter(
+        _ center: UNUserNotificationCenter,
+        didReceive response: UNNotificationResponse
+    ) async {
+        await MainActor.run {
+            NSApplication.shared.activate()
+            NSApplication.shared.windows.first(where: \.canBecomeMain)?.makeKeyAndOrderFront(nil)
+        }
+    }
+}
@@ -0,0 +1,43 @@
+import Dispatch
+
+enum MemoryPressureLevel: String {
+    case normal
+    case warning
+    case critical
+}
+
+/// Forwards the system's memory pressure transitions on the main actor.
+@MainActor
+final class MemoryPressureMonitor {
+    private(set) var level: MemoryPressureLevel = .normal
+    private var source: (any DispatchSourceMemoryPressure)?
+
+    func start(onChange: @escaping @MainActor (MemoryPressureLevel) -> Void) {
+        guard source == nil else { return }
+
+        let source = DispatchSource.makeMemoryPressureSource(eventMask: [.normal, .warning, .critical], queue: .main)
+        source.setEventHandler { [weak self, weak source] in
+            guard let event = source?.data else { return }
+            MainActor.assumeIsolated {
+                let level: MemoryPressureLevel
+                if event.contains(.critical) {
+                    level = .critical
+                } else if event.contains(.warning) {
+                    level = .warning
+                } else {
+                    level = .normal
+                }
+                guard let self, level != self.level else { return }
+                self.level = level
+                onChange(level)
+            }
+        }
+        source.activate()
+        self.source = source
+    }
+
+    func stop() {
+        source?.cancel()
+        source = nil
+    }
+}
@@ -6,6 +6,7 @@ import SwiftUI
 struct MindMapCanvasPane: View {
     @Bindable var model: AppModel
 
+    @Environment(\.accessibilityReduceMotion) private var reduceMotion
     @State private var pan = CGSize(width: 24, height: 24)
     @State private var zoom: CGFloat = 0.65
     @State private var steadyPan = CGSize(width: 24, height: 24)
@@ -34,9 +35,9 @@ struct MindMapCanvasPane: View {
                 .gesture(panGesture.simultaneously(with: zoomGesture))
 
                 HStack(spacing: DesignSpacing.x2) {
-                    Label("Visible: \(visibleNodes.count)", systemImage: "scope")
-                    Label("Total: \(model.graphNodes.count)", systemImage: "point.3.connected.trianglepath.dotted")
-                    Label(String(format: "Zoom: %.2fx", zoom), systemImage: "plus.magnifyingglass")
+                    Label(String(localized: "Visible: \(visibleNodes.count)", bundle: .module), systemImage: "scope")
+                    Label(String(localized: "Total: \(model.graphNodes.count)", bundle: .module), systemImage: "point.3.connected.trianglepath.dotted")
+                    Label(String(localized: "Zoom: \(Double(zoom).formatted(.number.precision(.fractionLength(2))))x", bundle: .module), systemImage: "plus.magnifyingglass")
                 }
                 .font(.system(size: DesignTypography.caption, weight: .medium))
                 .padding(.horizontal, DesignSpacing.x3)
@@ -46,8 +47,8 @@ struct MindMapCanvasPane: View {
             }
         }
         .overlay(alignment: .bottomTrailing) {
-            Button("Reset View") {
-                withAnimation(.spring(response: DesignMotion.regular, dampingFraction: 0.86)) {
+            Button(String(localized: "Reset View", bundle: .module)) {
+                withAnimation(reduceMotion ? nil : .spring(response: DesignMotion.regular, dampingFraction: 0.86)) {
                     pan = CGSize(width: 24, height: 24)
                     zoom = 0.65
                     steadyPan = pan
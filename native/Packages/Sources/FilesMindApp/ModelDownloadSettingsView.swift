@@ -0,0 +1,103 @@
+import AppCore
+import DesignSystem
+import SwiftUI
+
+/// Settings pane for model downloads: the proxy override next to what the system provides, and a bandwidth cap.
+struct ModelDownloadSettingsView: View {
+    private static let megabyte = 1_000_000
+    private static let limits: [Int?] = [nil, 1, 5, 10, 50].map { $0.map { $0 * megabyte } }
+
+    let model: AppModel
+
+    @State private var proxyText = ""
+    @State private var systemProxy = SystemProxySetting.direct
+    @State private var isInvalid = false
+
+    var body: some View {
+        Form {
+            Section {
+                LabeledContent(String(localized: "System proxy", bundle: .module), value: systemProxyDescription)
+
+                TextField(
+                    String(localized: "Proxy override", bundle: .module),
+                    text: $proxyText,
+                    prompt: Text("host:port", bundle: .module)
+                )
+                .onSubmit(apply)
+                .onChange(of: proxyText) { isInvalid = false }
+
+                if isInvalid {
+                    Text("Enter a host and port, such as proxy.example.com:3128.", bundle: .module)
+                        .font(.system(size: DesignTypography.caption))
+                        .foregroundStyle(.red)
+                }
+
+                LabeledContent(String(localized: "Downloads use", bundle: .module), value: effectiveProxyDescription)
+
+                Picker(
+                    String(localized: "Download speed", bundle: .module),
+                    selection: Binding(get: { model.modelDownloadLimit }, set: { model.setModelDownloadLimit($0) })
+                ) {
+                    ForEach(Self.limits, id: \.self) { limit in
+                        Text(Self.title(forLimit: limit)).tag(limit)
+                    }
+                }
+
+                HStack {
+                    Spacer()
+                    Button(String(localized: "Use System Settings", bundle: .module)) {
+                        proxyText = ""
+                        apply()
+                    }
+                    .disabled(model.modelDownloadProxy.isEmpty)
+                    Button(String(localized: "Apply", bundle: .module), action: apply)
+                        .keyboardShortcut(.defaultAction)
+                        .disabled(proxyText.trimmingCharacters(in: .whitespacesAndNewlines) == model.modelDownloadProxy)
+                }
+            } header: {
+                Text("Model Downloads", bundle: .module)
+            } footer: {
+                Text("A download in progress keeps its proxy but picks up a new speed limit right away.", bundle: .module)
+                    .font(.system(size: DesignTypography.caption))
+                    .foregroundStyle(.secondary)
+            }
+        }
+        .formStyle(.grouped)
+        .frame(width: 480)
+        .onAppear {
+            proxyText = model.modelDownloadProxy
+            systemProxy = SystemProxySetting.current()
+        }
+    }
+
+    private var systemProxyDescription: String {
+        switch systemProxy {
+        case .direct:
+            return String(localized: "None", bundle: .module)
+        case .manual(let proxy):
+            return proxy.hostAndPort
+        case .automatic(let pacURL?):
+            return String(localized: "Automatic (\(pacURL.absoluteString))", bundle: .module)
+        case .automatic(nil):
+            return String(localized: "Automatic", bundle: .module)
+        }
+    }
+
+    private var effectiveProxyDescription: String {
+        guard let proxyOverride = ProxyOverride(string: model.modelDownloadProxy) else {
+            return String(localized: "System settings", bundle: .module)
+        }
+        return proxyOverride.hostAndPort
+    }
+
+    private static func title(forLimit limit: Int?) -> String {
+        guard let limit else {
+            return String(localized: "Unlimited", bundle: .module)
+        }
+        return String(localized: "\(limit / megabyte) MB/s", bundle: .module)
+    }
+
+    private func apply() {
+        isInvalid = !model.setModelDownloadProxy(proxyText)
+    }
+}
@@ -0,0 +1,220 @@
+import AppKit
+import DesignSystem
+import Domain
+import SearchKit
+import SwiftUI
+
+/// A floating search field over whatever app is frontmost. Picking a hit brings the main window forward with that
+/// chunk selected; Escape or clicking elsewhere dismisses it.
+@MainActor
+final class QuickSearchPanelController {
+    static let resultLimit = 8
+
+    private unowned let model: AppModel
+    private var panel: QuickSearchPanel?
+
+    init(model: AppModel) {
+        self.model = model
+    }
+
+    func toggle() {
+        if panel?.isVisible == true {
+            panel?.close()
+        } else {
+            show()
+        }
+    }
+
+    func show() {
+        let panel = self.panel ?? makePanel()
+        self.panel = panel
+        panel.center()
+        panel.makeKeyAndOrderFront(nil)
+    }
+
+    private func makePanel() -> QuickSearchPanel {
+        let view = QuickSearchView(
+            search: { [weak self] query in await self?.search(query) ?? [] },
+            documentTitle: { [weak self] documentID in
+                self?.model.importedDocuments.first(where: { $0.id == documentID })?.title
+            },
+            onOpen: { [weak self] ranked, query in self?.open(ranked, query: query) }
+        )
+        return QuickSearchPanel(rootView: view)
+    }
+
+    private func search(_ query: String) async -> [RankedChunk] {
+        do {
+            return try await model.container.searchService.search(
+                keyword: query,
+                embedding: [],
+                limit: Self.resultLimit,
+                keywordWeight: 1.0,
+                vectorWeight: 0.0
+            )
+        } catch {
+            model.container.telemetry.warning("Quick search failed: \(error.localizedDescription)")
+            return []
+        }
+    }
+
+    private func open(_ ranked: RankedChunk, query: String) {
+        panel?.close()
+        NSApplication.shared.activate()
+        NSApplication.shared.windows.first(where: \.canBecomeMain)?.makeKeyAndOrderFront(nil)
+        model.searchQuery = query
+        model.runSearch()
+        model.selectSearchResult(ranked)
+    }
+}
+
+/// Non-activating, so the app the user was in stays frontmost until a hit is opened.
+private final class QuickSearchPanel: NSPanel {
+    init(rootView: QuickSearchView) {
+        super.init(
+            contentRect: NSRect(x: 0, y: 0, width: 620, height: 420),
+            styleMask: [.titled, .fullSizeContentView, .nonactivatingPanel],
+            backing: .buffered,
+            defer: true
+        )
+        titleVisibility = .hidden
+        titlebarAppearsTransparent = true
+        isMovableByWindowBackground = true
+        isReleasedWhenClosed = false
+        hidesOnDeactivate = false
+        level = .floating
+        collectionBehavior = [.canJoinAllSpaces, .fullScreenAuxiliary]
+        contentView = NSHostingView(rootView: rootView)
+    }
+
+    override var canBecomeKey: Bool { true }
+
+    override func cancelOperation(_ sender: Any?) {
+        close()
+    }
+
+    override func resignKey() {
+        super.resignKey()
+        close()
+    }
+}
+
+private struct QuickSearchView: View {
+    let search: @MainActor (String) async -> [RankedChunk]
+    let documentTitle: @MainActor (UUID) -> String?
+    let onOpen: @MainActor (RankedChunk, String) -> Void
+
+    @State private var query = ""
+    @State private var results: [RankedChunk] = []
+    @State private var highlightedIndex = 0
+    @FocusState private var isFieldFocused: Bool
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: DesignSpacing.x2) {
+            TextField(String(localized: "Search the library", bundle: .module), text: $query)
+                .textFieldStyle(.plain)
+                .font(.system(size: DesignTypography.hero))
+                .focused($isFieldFocused)
+                .onSubmit(openHighlighted)
+                .onKeyPress(.downArrow) {
+                    moveHighlight(by: 1)
+                    return .handled
+                }
+                .onKeyPress(.upArrow) {
+                    moveHighlight(by: -1)
+                    return .handled
+                }
+
+            Divider()
+
+            if results.isEmpty {
+                Text(
+                    query.trimmingCharacters(in: .whitespacesAndNewlines).isEmpty
+                        ? String(localized: "Type keywords to search indexed chunks.", bundle: .module)
+                        : String(localized: "No results to display.", bundle: .module)
+                )
+                .font(.system(size: DesignTypography.body))
+                .foregroundStyle(.secondary)
+                Spacer(minLength: 0)
+            } else {
+                ScrollView {
+                    VStack(alignment: .leading, spacing: DesignSpacing.x1) {
+                        ForEach(Array(results.enumerated()), id: \.element.chunk.id) { index, ranked in
+                            QuickSearchResultRow(
+                                ranked: ranked,
+                                documentTitle: documentTitle(ranked.chunk.documentID),
+                                isHighlighted: index == highlightedIndex
+                            ) {
+                                onOpen(ranked, query.trimmingCharacters(in: .whitespacesAndNewlines))
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        .padding(DesignSpacing.x4)
+        .frame(width: 620, height: 420, alignment: .top)
+        .onAppear { isFieldFocused = true }
+        .task(id: query) {
+            let trimmed = query.trimmingCharacters(in: .whitespacesAndNewlines)
+            guard !trimmed.isEmpty else {
+                results = []
+                return
+            }
+            // A new keystroke cancels this task, so only a pause in typing reaches the index.
+            try? await Task.sleep(for: .milliseconds(150))
+            guard !Task.isCancelled else { return }
+            let hits = await search(trimmed)
+            guard !Task.isCancelled else { return }
+            results = hits
+            highlightedIndex = 0
+        }
+    }
+
+    private func moveHighlight(by offset: Int) {
+        guard !results.isEmpty else { return }
+        highlightedIndex = min(max(highlightedIndex + offset, 0), results.count - 1)
+    }
+
+    private func openHighlighted() {
+        guard results.indices.contains(highlightedIndex) else { return }
+        onOpen(results[highlightedIndex], query.trimmingCharacters(in: .whitespacesAndNewlines))
+    }
+}
+
+private struct QuickSearchResultRow: View {
+    let ranked: RankedChunk
+    let documentTitle: String?
+    let isHighlighted: Bool
+    let onSelect: () -> Void
+
+    var body: some View {
+        Button(action: onSelect) {
+            VStack(alignment: .leading, spacing: DesignSpacing.x1) {
+                HStack {
+                    Text(documentTitle ?? String(localized: "Untitled document", bundle: .module))
+                        .font(.system(size: DesignTypography.caption, weight: .semibold))
+                        .lineLimit(1)
+                    Spacer(minLength: 8)
+                    if let page = ranked.chunk.sourcePageIndex {
+                        Text("P\(page + 1)")
+                            .font(.system(size: DesignTypography.caption, weight: .semibold, design: .rounded))
+                            .foregroundStyle(.secondary)
+                    }
+                }
+
+                Text(ranked.chunk.text)
+                    .font(.system(size: DesignTypography.body))
+                    .lineLimit(2)
+                    .foregroundStyle(.secondary)
+            }
+            .padding(DesignSpacing.x2)
+            .frame(maxWidth: .infinity, alignment: .leading)
+            .background(
+                isHighlighted ? Color.accentColor.opacity(0.15) : Color.clear,
+                in: RoundedRectangle(cornerRadius: DesignCornerRadius.small)
+            )
+        }
+        .buttonStyle(.plain)
+    }
+}
@@ -0,0 +1,76 @@
+import AppCore
+import CoreSpotlight
+import Domain
+import Foundation
+import UniformTypeIdentifiers
+
+/// Mirrors library documents into Spotlight so system search can jump straight into FilesMind.
+@MainActor
+final class SpotlightIndexer {
+    static let domainIdentifier = "filesmind.documents"
+
+    private let telemetry: Telemetry
+    private var indexedIDs: Set<UUID> = []
+
+    init(telemetry: Telemetry) {
+        self.telemetry = telemetry
+    }
+
+    // Like notifications, Spotlight needs a bundle identifier; skip indexing under `swift run`.
+    private var index: CSSearchableIndex? {
+        Bundle.main.bundleIdentifier == nil ? nil : CSSearchableIndex.default()
+    }
+
+    /// Only documents not yet indexed this session are sent; Spotlight replaces items by identifier.
+    func index(_ documents: [ImportedDocumentRecord], sections: [UUID: [ParsedSection]]) {
+        guard let index else { return }
+        let pending = documents.filter { !indexedIDs.contains($0.id) }
+        guard !pending.isEmpty else { return }
+
+        let items = pending.map { document in
+            let attributes = CSSearchableItemAttributeSet(contentType: document.sourceType == .pdf ? .pdf : .text)
+            attributes.title = document.title
+            attributes.displayName = document.title
+            let fileName = URL(fileURLWithPath: document.sourcePath).lastPathComponent
+            attributes.contentDescription = String(localized: "\(document.chunkCount) chunks · \(fileName)", bundle: .module)
+            attributes.keywords = (sections[document.id] ?? []).prefix(20).map(\.title)
+            attributes.url = DeepLink.openDocument(document.id).url
+            if let languageCode = document.languageCode {
+                attributes.languages = [languageCode]
+            }
+            return CSSearchableItem(
+                uniqueIdentifier: document.id.uuidString,
+                domainIdentifier: Self.domainIdentifier,
+                attributeSet: attributes
+            )
+        }
+
+        indexedIDs.formUnion(pending.map(\.id))
+        let telemetry = telemetry
+        index.indexSearchableItems(items) { error in
+            if let error {
+                telemetry.warning("Spotlight indexing failed: \(error.localizedDescription)")
+            }
+        }
+    }
+
+    func remove(documentID: UUID) {
+        indexedIDs.remove(documentID)
+        index?.deleteSearchableItems(withIdentifiers: [documentID.uuidString])
+    }
+
+    func removeAll() {
+        indexedIDs.removeAll()
+        index?.deleteSearchableItems(withDomainIdentifiers: [Self.domainIdentifier])
+    }
+
+    static func documentID(from activity: NSUserActivity) -> UUID? {
+        guard
+            activity.activityType == CSSearchableItemActionType,
+            let identifier = activity.userInfo?[CSSearchableItemActivityIdentifier] as? String
+        else {
+            return nil
+        }
+        return UUID(uuidString: identifier)
+    }
+}
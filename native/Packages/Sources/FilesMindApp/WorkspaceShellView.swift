@@ -5,6 +5,7 @@ import SwiftUI
 
 struct WorkspaceShellView: View {
     @Bindable var model: AppModel
+    @State private var isDropTargeted = false
 
     var body: some View {
         NavigationSplitView {
@@ -18,15 +19,32 @@ struct WorkspaceShellView: View {
                 .frame(minWidth: 600)
         }
         .navigationTitle("filesMind")
+        .dropDestination(for: URL.self) { urls, _ in
+            let fileURLs = urls.filter(\.isFileURL)
+            model.importDroppedItems(fileURLs)
+            return !fileURLs.isEmpty
+        } isTargeted: { targeted in
+            isDropTargeted = targeted
+        }
+        .overlay {
+            if isDropTargeted {
+                RoundedRectangle(cornerRadius: DesignCornerRadius.large)
+                    .strokeBorder(Color.accentColor, style: StrokeStyle(lineWidth: 3, dash: [8, 6]))
+                    .padding(DesignSpacing.x2)
+                    .allowsHitTesting(false)
+            }
+        }
     }
 }
 
 private struct SidebarPane: View {
     @Bindable var model: AppModel
 
+    @FocusState private var isDocumentListFocused: Bool
+
     var body: some View {
         VStack(alignment: .leading, spacing: DesignSpacing.x4) {
-            Text("Workspace")
+            Text("Workspace", bundle: .module)
                 .font(.system(size: DesignTypography.hero, weight: .semibold))
 
             Text(model.workspaceStatus)
@@ -42,6 +60,31 @@ private struct SidebarPane: View {
                     .textSelection(.enabled)
             }
 
+            if !model.networkStatus.isOnline {
+                Label(String(localized: "Offline — model downloads resume when you reconnect", bundle: .module), systemImage: "wifi.slash")
+                    .font(.system(size: DesignTypography.caption, weight: .medium))
+                    .foregroundStyle(.secondary)
+            } else if model.networkStatus.isMetered {
+                Label(String(localized: "On a metered connection", bundle: .module), systemImage: "antenna.radiowaves.left.and.right")
+                    .font(.system(size: DesignTypography.caption, weight: .medium))
+                    .foregroundStyle(.secondary)
+            }
+
+            if let trashed = model.lastTrashedDocument {
+                HStack(spacing: DesignSpacing.x2) {
+                    Label(String(localized: "Moved \(trashed.title) to the Trash", bundle: .module), systemImage: "trash")
+                        .font(.system(size: DesignTypography.caption, weight: .medium))
+                        .foregroundStyle(.secondary)
+                        .lineLimit(1)
+                    Spacer(minLength: DesignSpacing.x2)
+                    Button(String(localized: "Undo", bundle: .module)) {
+                        model.restoreLastTrashedDocument()
+                    }
+                    .buttonStyle(.borderless)
+                    .font(.system(size: DesignTypography.caption, weight: .semibold))
+                }
+            }
+
             if let lastError = model.lastError {
                 Text(lastError)
                     .font(.system(size: DesignTypography.body))
@@ -51,23 +94,23 @@ private struct SidebarPane: View {
 
             Divider()
 
-            Button("Choose Workspace") {
+            Button(String(localized: "Choose Workspace", bundle: .module)) {
                 model.chooseWorkspace()
             }
             .buttonStyle(.borderedProminent)
 
-            Button("Import Files") {
+            Button(String(localized: "Import Files", bundle: .module)) {
                 model.chooseAndEnqueueImports()
             }
             .buttonStyle(.bordered)
 
             Divider()
 
-            Text("Imported Documents")
+            Text("Imported Documents", bundle: .module)
                 .font(.system(size: DesignTypography.title, weight: .semibold))
 
             if model.importedDocuments.isEmpty {
-                Text("No imported documents yet.")
+                Text("No imported documents yet.", bundle: .module)
                     .font(.system(size: DesignTypography.body))
                     .foregroundStyle(.secondary)
             } else {
@@ -75,20 +118,46 @@ private struct SidebarPane: View {
                     VStack(alignment: .leading, spacing: DesignSpacing.x2) {
                         ForEach(model.importedDocuments) { document in
                             ImportedDocumentRow(
+                                model: model,
                                 document: document,
                                 isSelected: document.id == model.selectedDocumentID
                             ) {
                                 model.selectImportedDocument(document)
+                                isDocumentListFocused = true
+                            }
+                            .contextMenu {
+                                Button(String(localized: "Open", bundle: .module)) {
+                                    model.openWithDefaultApp(document)
+                                }
+                                Button(String(localized: "Show in Finder", bundle: .module)) {
+                                    model.revealInFinder(document)
+                                }
+                                Divider()
+                                Button(
+                                    model.erasesRemovedDocumentsSecurely
+                                        ? String(localized: "Erase Permanently...", bundle: .module)
+                                        : String(localized: "Move to Trash", bundle: .module)
+                                ) {
+                                    model.moveToTrash(document)
+                                }
                             }
                         }
                     }
                 }
                 .frame(maxHeight: 220)
+                .focusable()
+                .focused($isDocumentListFocused)
+                // Finder's Cmd-Down, handled here rather than as a menu shortcut so text fields keep the key.
+                .onKeyPress(.downArrow, phases: .down) { press in
+                    guard press.modifiers == .command, let document = model.selectedDocument else { return .ignored }
+                    model.openWithDefaultApp(document)
+                    return .handled
+                }
             }
 
             if !model.selectedDocumentSections.isEmpty {
                 VStack(alignment: .leading, spacing: DesignSpacing.x2) {
-                    Text("Outline")
+                    Text("Outline", bundle: .module)
                         .font(.system(size: DesignTypography.title, weight: .semibold))
                     ForEach(model.selectedDocumentSections.prefix(10)) { section in
                         Text("\(String(repeating: "  ", count: max(0, section.level - 1)))• \(section.title)")
@@ -104,12 +173,12 @@ private struct SidebarPane: View {
             if let selectedDocument = model.selectedDocument, !selectedDocument.lowQualityPages.isEmpty {
                 let pageList = selectedDocument.lowQualityPages.map { String($0 + 1) }.joined(separator: ", ")
                 VStack(alignment: .leading, spacing: DesignSpacing.x2) {
-                    Text("PDF Quality")
+                    Text("PDF Quality", bundle: .module)
                         .font(.system(size: DesignTypography.title, weight: .semibold))
-                    Text("Low-quality pages: \(pageList)")
+                    Text("Low-quality pages: \(pageList)", bundle: .module)
                         .font(.system(size: DesignTypography.caption, weight: .regular, design: .monospaced))
                         .foregroundStyle(.orange)
-                    Button("Re-parse Low-quality Pages") {
+                    Button(String(localized: "Re-parse Low-Quality Pages", bundle: .module)) {
                         model.requestReparseLowQualityPages()
                     }
                     .buttonStyle(.bordered)
@@ -126,9 +195,9 @@ private struct SidebarPane: View {
             Spacer(minLength: DesignSpacing.x3)
 
             VStack(alignment: .leading, spacing: DesignSpacing.x2) {
-                Text("Aesthetic Baseline")
+                Text("Aesthetic Baseline", bundle: .module)
                     .font(.system(size: DesignTypography.title, weight: .medium))
-                Text("Calm · Precision · Depth · Focus")
+                Text("Calm · Precision · Depth · Focus", bundle: .module)
                     .font(.system(size: DesignTypography.body))
                     .foregroundStyle(.secondary)
             }
@@ -141,37 +210,64 @@ private struct SidebarPane: View {
 }
 
 private struct ImportedDocumentRow: View {
+    let model: AppModel
     let document: ImportedDocumentRecord
     let isSelected: Bool
     let onSelect: () -> Void
 
+    @State private var thumbnail: NSImage?
+
     var body: some View {
         Button(action: onSelect) {
-            VStack(alignment: .leading, spacing: DesignSpacing.x1) {
-                HStack {
-                    Text(document.title)
-                        .font(.system(size: DesignTypography.body, weight: .medium))
-                        .lineLimit(1)
-                    Spacer(minLength: 8)
-                    Text(document.sourceType.rawValue.uppercased())
-                        .font(.system(size: DesignTypography.caption, weight: .semibold))
-                        .foregroundStyle(.secondary)
-                }
-
-                HStack(spacing: DesignSpacing.x2) {
-                    Text("\(document.chunkCount) chunks")
-                    if !document.lowQualityPages.isEmpty {
-                        Text("fallback pages: \(document.lowQualityPages.count)")
-                            .foregroundStyle(.orange)
-                    }
-                }
-                .font(.system(size: DesignTypography.caption))
+            HStack(alignment: .top, spacing: DesignSpacing.x2) {
+                thumbnailView
+                    .frame(width: 32, height: 40)
+                documentDetails
             }
             .padding(DesignSpacing.x2)
             .frame(maxWidth: .infinity, alignment: .leading)
             .background(backgroundStyle, in: RoundedRectangle(cornerRadius: DesignCornerRadius.small))
         }
         .buttonStyle(.plain)
+        .task(id: document.id) {
+            thumbnail = await model.thumbnail(for: document, maxPixelSize: 80)
+        }
+    }
+
+    @ViewBuilder
+    private var thumbnailView: some View {
+        if let thumbnail {
+            Image(nsImage: thumbnail)
+                .resizable()
+                .aspectRatio(contentMode: .fit)
+        } else {
+            Image(systemName: document.sourceType == .pdf ? "doc.richtext" : "doc.text")
+                .font(.system(size: DesignTypography.title))
+                .foregroundStyle(.secondary)
+        }
+    }
+
+    private var documentDetails: some View {
+        VStack(alignment: .leading, spacing: DesignSpacing.x1) {
+            HStack {
+                Text(document.title)
+                    .font(.system(size: DesignTypography.body, weight: .medium))
+                    .lineLimit(1)
+                Spacer(minLength: 8)
+                Text(document.sourceType.rawValue.uppercased())
+                    .font(.system(size: DesignTypography.caption, weight: .semibold))
+                    .foregroundStyle(.secondary)
+            }
+
+            HStack(spacing: DesignSpacing.x2) {
+                Text("\(document.chunkCount) chunks", bundle: .module)
+                if !document.lowQualityPages.isEmpty {
+                    Text("fallback pages: \(document.lowQualityPages.count)", bundle: .module)
+                        .foregroundStyle(.orange)
+                }
+            }
+            .font(.system(size: DesignTypography.caption))
+        }
     }
 
     private var backgroundStyle: Color {
@@ -192,32 +288,47 @@ private func reparseStatusColor(_ status: ReparseJobStatus) -> Color {
     }
 }
 
+private func reparseStatusTitle(_ status: ReparseJobStatus) -> String {
+    switch status {
+    case .queued:
+        return String(localized: "Queued", bundle: .module)
+    case .running:
+        return String(localized: "Running", bundle: .module)
+    case .completed:
+        return String(localized: "Completed", bundle: .module)
+    case .failed:
+        return String(localized: "Failed", bundle: .module)
+    }
+}
+
 private struct ReparseComparisonCard: View {
     @Bindable var model: AppModel
     let comparison: ReparseComparison
 
+    @Environment(\.accessibilityReduceMotion) private var reduceMotion
+
     var body: some View {
         VStack(alignment: .leading, spacing: DesignSpacing.x3) {
             HStack {
-                Label("Reparse Comparison", systemImage: "waveform.path.ecg.rectangle")
+                Label(String(localized: "Reparse Comparison", bundle: .module), systemImage: "waveform.path.ecg.rectangle")
                     .font(.system(size: DesignTypography.bodyLarge, weight: .semibold))
                 Spacer(minLength: 8)
-                Text(comparison.status.rawValue.uppercased())
+                Text(reparseStatusTitle(comparison.status).uppercased())
                     .font(.system(size: DesignTypography.caption, weight: .semibold))
                     .foregroundStyle(reparseStatusColor(comparison.status))
             }
 
             HStack(spacing: DesignSpacing.x4) {
-                metricBlock(value: "\(comparison.beforeCount)", label: "Before")
-                metricBlock(value: "\(comparison.afterCount)", label: "After")
-                metricBlock(value: "\(comparison.resolvedCount)", label: "Resolved")
-                metricBlock(value: "\(Int((comparison.resolvedRatio * 100).rounded()))%", label: "Rate")
+                metricBlock(value: "\(comparison.beforeCount)", label: String(localized: "Before", bundle: .module))
+                metricBlock(value: "\(comparison.afterCount)", label: String(localized: "After", bundle: .module))
+                metricBlock(value: "\(comparison.resolvedCount)", label: String(localized: "Resolved", bundle: .module))
+                metricBlock(value: "\(Int((comparison.resolvedRatio * 100).rounded()))%", label: String(localized: "Rate", bundle: .module))
             }
 
             HStack(spacing: DesignSpacing.x2) {
                 Image(systemName: "point.3.connected.trianglepath.dotted")
                     .foregroundStyle(.secondary)
-                Text("Map synced")
+                Text("Map synced", bundle: .module)
                     .font(.system(size: DesignTypography.caption, weight: .medium))
                     .foregroundStyle(.secondary)
                 Spacer(minLength: 6)
@@ -239,14 +350,14 @@ private struct ReparseComparisonCard: View {
             }
 
             Button {
-                withAnimation(.spring(response: DesignMotion.regular, dampingFraction: 0.9)) {
+                withAnimation(reduceMotion ? nil : .spring(response: DesignMotion.regular, dampingFraction: 0.9)) {
                     model.toggleReparseDiffExpanded()
                 }
             } label: {
                 HStack(spacing: DesignSpacing.x2) {
                     Image(systemName: model.reparseDiffExpanded ? "chevron.down" : "chevron.right")
                         .font(.system(size: 11, weight: .semibold))
-                    Text("Page Diff")
+                    Text("Page Diff", bundle: .module)
                         .font(.system(size: DesignTypography.caption, weight: .semibold))
                     Spacer(minLength: 8)
                 }
@@ -277,7 +388,7 @@ private struct ReparseComparisonCard: View {
                     .padding(.bottom, DesignSpacing.x1)
 
                     if model.reparseDiffPages.isEmpty {
-                        Text("No pages in this scope.")
+                        Text("No pages in this scope.", bundle: .module)
                             .font(.system(size: DesignTypography.caption))
                             .foregroundStyle(.secondary)
                     } else {
@@ -298,7 +409,7 @@ private struct ReparseComparisonCard: View {
                                             )
                                     }
                                     .buttonStyle(.plain)
-                                    .help("Filter search results by page \(page + 1)")
+                                    .help(String(localized: "Filter search results by page \(page + 1)", bundle: .module))
                                 }
                             }
                             .padding(.vertical, 1)
@@ -306,17 +417,17 @@ private struct ReparseComparisonCard: View {
                     }
 
                     pageLine(
-                        title: "Resolved",
+                        title: String(localized: "Resolved", bundle: .module),
                         pages: comparison.resolvedPages,
                         color: .green
                     )
                     pageLine(
-                        title: "Remaining",
+                        title: String(localized: "Remaining", bundle: .module),
                         pages: comparison.remainingPages,
                         color: .orange
                     )
                     pageLine(
-                        title: "Input",
+                        title: String(localized: "Input", bundle: .module),
                         pages: comparison.beforePages,
                         color: .secondary
                     )
@@ -361,11 +472,11 @@ private struct ReparseComparisonCard: View {
     private func scopeTitle(_ scope: ReparseDiffScope) -> String {
         switch scope {
         case .resolved:
-            return "Resolved"
+            return String(localized: "Resolved", bundle: .module)
         case .remaining:
-            return "Remaining"
+            return String(localized: "Remaining", bundle: .module)
         case .input:
-            return "Input"
+            return String(localized: "Input", bundle: .module)
         }
     }
 
@@ -410,7 +521,7 @@ private struct ReparseComparisonCard: View {
     }
 
     private func pageLine(title: String, pages: [Int], color: Color) -> some View {
-        let rendered = pages.isEmpty ? "none" : pages.map { String($0 + 1) }.joined(separator: ", ")
+        let rendered = pages.isEmpty ? String(localized: "none", bundle: .module) : pages.map { String($0 + 1) }.joined(separator: ", ")
         return HStack(alignment: .top, spacing: DesignSpacing.x2) {
             Text(title)
                 .font(.system(size: DesignTypography.caption, weight: .semibold))
@@ -429,34 +540,52 @@ private struct ImportQueuePane: View {
 
     var body: some View {
         VStack(alignment: .leading, spacing: DesignSpacing.x3) {
-            Text("Import Queue")
+            Text("Import Queue", bundle: .module)
                 .font(.system(size: DesignTypography.hero, weight: .semibold))
 
             Group {
                 if model.importJobs.isEmpty {
                     VStack(alignment: .leading, spacing: DesignSpacing.x2) {
-                        Text("No jobs yet")
+                        Text("No jobs yet", bundle: .module)
                             .font(.system(size: DesignTypography.bodyLarge, weight: .medium))
-                        Text("Use \"Import Files\" to enqueue Markdown/PDF documents.")
+                        Text("Use \"Import Files\" to enqueue Markdown/PDF documents.", bundle: .module)
                             .font(.system(size: DesignTypography.body))
                             .foregroundStyle(.secondary)
                     }
                     .padding(.top, DesignSpacing.x2)
                 } else {
                     List(model.importJobs) { job in
-                        ImportJobRow(job: job)
+                        ImportJobRow(job: job) {
+                            model.unlockImportJob(job)
+                        }
                     }
                     .listStyle(.inset)
                 }
             }
 
+            if !model.failedImports.isEmpty {
+                VStack(alignment: .leading, spacing: DesignSpacing.x2) {
+                    Text("Failed Imports", bundle: .module)
+                        .font(.system(size: DesignTypography.title, weight: .semibold))
+                    ForEach(model.failedImports) { record in
+                        FailedImportRow(
+                            record: record,
+                            onRetry: { model.retryFailedImport(record) },
+                            onDismiss: { model.dismissFailedImport(record) }
+                        )
+                    }
+                }
+                .padding(DesignSpacing.x3)
+                .background(.thinMaterial, in: RoundedRectangle(cornerRadius: DesignCornerRadius.medium))
+            }
+
             Divider()
 
             VStack(alignment: .leading, spacing: DesignSpacing.x2) {
-                Text("Search")
+                Text("Search", bundle: .module)
                     .font(.system(size: DesignTypography.title, weight: .semibold))
                 HStack(spacing: DesignSpacing.x2) {
-                    TextField("Search indexed chunks", text: $model.searchQuery)
+                    TextField(String(localized: "Search indexed chunks", bundle: .module), text: $model.searchQuery)
                         .textFieldStyle(.roundedBorder)
                         .onSubmit {
                             model.runSearch()
@@ -469,11 +598,22 @@ private struct ImportQueuePane: View {
                             ProgressView()
                                 .controlSize(.small)
                         } else {
-                            Text("Run")
+                            Text("Run", bundle: .module)
                         }
                     }
                     .buttonStyle(.borderedProminent)
                     .disabled(model.isSearching)
+
+                    Menu(String(localized: "Export", bundle: .module)) {
+                        Button(String(localized: "CSV...", bundle: .module)) {
+                            model.exportSearchResults(format: .csv)
+                        }
+                        Button(String(localized: "JSON Lines...", bundle: .module)) {
+                            model.exportSearchResults(format: .jsonl)
+                        }
+                    }
+                    .fixedSize()
+                    .disabled(model.searchResults.isEmpty || model.isExportingResults)
                 }
 
                 Text(model.searchStatus)
@@ -482,10 +622,10 @@ private struct ImportQueuePane: View {
 
                 if let page = model.activePageFilter {
                     HStack(spacing: DesignSpacing.x2) {
-                        Label("Page filter: \(page + 1)", systemImage: "line.3.horizontal.decrease.circle")
+                        Label(String(localized: "Page filter: \(page + 1)", bundle: .module), systemImage: "line.3.horizontal.decrease.circle")
                             .font(.system(size: DesignTypography.caption, weight: .medium))
                             .foregroundStyle(.secondary)
-                        Button("Clear") {
+                        Button(String(localized: "Clear", bundle: .module)) {
                             model.togglePageFilter(page)
                         }
                         .buttonStyle(.borderless)
@@ -495,7 +635,7 @@ private struct ImportQueuePane: View {
             }
 
             if model.searchResults.isEmpty {
-                Text("No results to display.")
+                Text("No results to display.", bundle: .module)
                     .font(.system(size: DesignTypography.body))
                     .foregroundStyle(.secondary)
             } else {
@@ -510,7 +650,7 @@ private struct ImportQueuePane: View {
             if let selectedChunkPreview = model.selectedChunkPreview {
                 Divider()
                 VStack(alignment: .leading, spacing: DesignSpacing.x1) {
-                    Text("Focused Chunk")
+                    Text("Focused Chunk", bundle: .module)
                         .font(.system(size: DesignTypography.caption, weight: .semibold))
                     Text(selectedChunkPreview)
                         .font(.system(size: DesignTypography.body))
@@ -528,6 +668,7 @@ private struct ImportQueuePane: View {
 
 private struct ImportJobRow: View {
     let job: ImportJob
+    let onUnlock: () -> Void
 
     var body: some View {
         VStack(alignment: .leading, spacing: DesignSpacing.x2) {
@@ -536,7 +677,12 @@ private struct ImportJobRow: View {
                     .font(.system(size: DesignTypography.bodyLarge, weight: .medium))
                     .lineLimit(1)
                 Spacer(minLength: 8)
-                Text(job.status.rawValue.capitalized)
+                if job.status == .passwordRequired {
+                    Button(String(localized: "Unlock…", bundle: .module), action: onUnlock)
+                        .buttonStyle(.borderless)
+                        .font(.system(size: DesignTypography.caption, weight: .semibold))
+                }
+                Text(statusTitle)
                     .font(.system(size: DesignTypography.caption, weight: .semibold))
                     .foregroundStyle(statusColor)
                     .textCase(.uppercase)
@@ -554,6 +700,21 @@ private struct ImportJobRow: View {
         .padding(.vertical, DesignSpacing.x1)
     }
 
+    private var statusTitle: String {
+        switch job.status {
+        case .queued:
+            return String(localized: "Queued", bundle: .module)
+        case .parsing:
+            return String(localized: "Parsing", bundle: .module)
+        case .indexed:
+            return String(localized: "Indexed", bundle: .module)
+        case .failed:
+            return String(localized: "Failed", bundle: .module)
+        case .passwordRequired:
+            return String(localized: "Locked", bundle: .module)
+        }
+    }
+
     private var statusColor: Color {
         switch job.status {
         case .queued:
@@ -564,6 +725,36 @@ private struct ImportJobRow: View {
             return .green
         case .failed:
             return .red
+        case .passwordRequired:
+            return .yellow
+        }
+    }
+}
+
+private struct FailedImportRow: View {
+    let record: FailedImportRecord
+    let onRetry: () -> Void
+    let onDismiss: () -> Void
+
+    var body: some View {
+        VStack(alignment: .leading, spacing: DesignSpacing.x1) {
+            HStack {
+                Text(record.fileURL.lastPathComponent)
+                    .font(.system(size: DesignTypography.body, weight: .medium))
+                    .lineLimit(1)
+                Spacer(minLength: 8)
+                Button(String(localized: "Retry", bundle: .module), action: onRetry)
+                    .buttonStyle(.borderless)
+                Button(String(localized: "Dismiss", bundle: .module), action: onDismiss)
+                    .buttonStyle(.borderless)
+                    .foregroundStyle(.secondary)
+            }
+            .font(.system(size: DesignTypography.caption, weight: .semibold))
+
+            Text("\(record.lastError) · \(record.attemptCount) attempts", bundle: .module)
+                .font(.system(size: DesignTypography.caption))
+                .foregroundStyle(.red)
+                .lineLimit(2)
         }
     }
 }
@@ -576,7 +767,7 @@ private struct SearchResultRow: View {
         Button(action: onSelect) {
             VStack(alignment: .leading, spacing: DesignSpacing.x1) {
                 HStack {
-                    Text("Score \(String(format: "%.3f", ranked.score))")
+                    Text("Score \(ranked.score.formatted(.number.precision(.fractionLength(3))))", bundle: .module)
                         .font(.system(size: DesignTypography.caption, weight: .semibold))
                         .foregroundStyle(.secondary)
                     Spacer(minLength: 8)
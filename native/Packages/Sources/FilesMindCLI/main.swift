@@ -0,0 +1,149 @@
+import AppCore
+import Domain
+import Foundation
+import SearchKit
+import TelemetryKit
+
+/// Log lines go to stderr so stdout only carries progress and can be piped.
+private final class StandardErrorTelemetry: Telemetry, @unchecked Sendable {
+    private let verbose: Bool
+
+    init(verbose: Bool) {
+        self.verbose = verbose
+    }
+
+    func info(_ message: String) {
+        if verbose { write("[INFO] \(message)") }
+    }
+
+    func warning(_ message: String) {
+        write("[WARN] \(message)")
+    }
+
+    func error(_ message: String) {
+        write("[ERROR] \(message)")
+    }
+
+    private func write(_ line: String) {
+        FileHandle.standardError.write(Data((line + "\n").utf8))
+    }
+}
+
+private let usage = """
+usage: filesmind [--verbose] <command> [arguments]
+
+commands:
+  ingest <path>...          import files and folders into the library, waiting until indexing finishes
+  export <query> <dest>     write every search hit for <query> to <dest> (.csv or .jsonl)
+  help                      show this message
+
+environment:
+  FILESMIND_PROXY           host:port of a proxy for model downloads, overriding the system settings
+"""
+
+private func fail(_ message: String) -> Never {
+    FileHandle.standardError.write(Data("filesmind: \(message)\n".utf8))
+    exit(2)
+}
+
+private func ingest(_ paths: [String], container: AppContainer) async -> Int32 {
+    let urls = paths.map { URL(fileURLWithPath: $0).standardizedFileURL }
+    let candidates = ImportCandidateResolver().resolveExpandingDirectories(urls)
+    for rejection in candidates.rejected {
+        print("skipped  \(rejection.fileURL.path)")
+    }
+    guard !candidates.accepted.isEmpty else {
+        print("nothing to import")
+        return candidates.rejected.isEmpty ? 0 : 1
+    }
+
+    let stream = await container.importQueue.subscribe()
+    await EnqueueImportUseCase(queue: container.importQueue).execute(fileURLs: candidates.accepted)
+
+    let wanted = Set(candidates.accepted)
+    var reported: [UUID: ImportJobStatus] = [:]
+    var finished: [ImportJob] = []
+    for await jobs in stream {
+        for job in jobs where wanted.contains(job.fileURL) && reported[job.id] != job.status {
+            reported[job.id] = job.status
+            switch job.status {
+            case .queued:
+                continue
+            case .parsing:
+                print("parsing  \(job.fileURL.path)")
+            case .indexed:
+                print("indexed  \(job.fileURL.path)  \(job.message ?? "")")
+                finished.append(job)
+            case .failed:
+                print("failed   \(job.fileURL.path)  \(job.message ?? "")")
+                finished.append(job)
+            case .passwordRequired:
+                print("locked   \(job.fileURL.path)  password required, import it from the app")
+                finished.append(job)
+            }
+        }
+        if finished.count >= candidates.accepted.count {
+            break
+        }
+    }
+
+    let indexed = finished.filter { $0.status == .indexed }.count
+    print("done: \(indexed) indexed, \(finished.count - indexed) not indexed, \(candidates.rejected.count) skipped")
+    return indexed == finished.count && candidates.rejected.isEmpty ? 0 : 1
+}
+
+private func export(query: String, destination: String, container: AppContainer) async -> Int32 {
+    let destinationURL = URL(fileURLWithPath: destination).standardizedFileURL
+    guard let format = SearchExportFormat(rawValue: destinationURL.pathExtension.lowercased()) else {
+        fail("export destination must end in .csv or .jsonl")
+    }
+
+    do {
+        let results = try await container.searchService.search(keyword: query, embedding: [], limit: 100_000)
+        let documents = try await container.documentStore?.recentDocuments(limit: 100_000) ?? []
+        let written = try await SearchResultExporter(telemetry: container.telemetry).export(
+            results,
+            documents: Dictionary(documents.map { ($0.id, $0) }, uniquingKeysWith: { first, _ in first }),
+            format: format,
+            to: destinationURL
+        ) { progress in
+            print("exported \(progress.writtenRows)/\(progress.totalRows)")
+        }
+        print("done: \(written) rows written to \(destinationURL.path)")
+        return 0
+    } catch {
+        FileHandle.standardError.write(Data("filesmind: export failed: \(error.localizedDescription)\n".utf8))
+        return 1
+    }
+}
+
+var arguments = Array(CommandLine.arguments.dropFirst())
+let verbose = arguments.first == "--verbose"
+if verbose {
+    arguments.removeFirst()
+}
+
+guard let command = arguments.first else {
+    fail("missing command\n\(usage)")
+}
+
+let container = AppBootstrap.makeDefault(
+    installRoot: FilesMindPaths.modelsRootURL,
+    telemetry: StandardErrorTelemetry(verbose: verbose),
+    proxyOverride: ProcessInfo.processInfo.environment["FILESMIND_PROXY"].flatMap(ProxyOverride.init(string:))
+)
+let status: Int32
+switch command {
+case "ingest":
+    guard arguments.count > 1 else { fail("ingest needs at least one path") }
+    status = await ingest(Array(arguments.dropFirst()), container: container)
+case "export":
+    guard arguments.count == 3 else { fail("export needs a query and a destination") }
+    status = await export(query: arguments[1], destination: arguments[2], container: container)
+case "help", "--help", "-h":
+    print(usage)
+    status = 0
+default:
+    fail("unknown command '\(command)'\n\(usage)")
+}
+exit(status)
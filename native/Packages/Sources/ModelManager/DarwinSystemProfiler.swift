@@ -0,0 +1,76 @@
+import Domain
+import Foundation
+#if canImport(Darwin)
+import Darwin
+#endif
+
+public struct DarwinSystemProfiler: SystemProfiling {
+    public init() {}
+
+    public func currentProfile(volumeURL: URL) async throws -> SystemProfile {
+        let processInfo = ProcessInfo.processInfo
+
+        return SystemProfile(
+            cpuModel: sysctlString("machdep.cpu.brand_string") ?? "Unknown",
+            physicalCoreCount: sysctlInt("hw.physicalcpu") ?? processInfo.processorCount,
+            activeCoreCount: processInfo.activeProcessorCount,
+            physicalMemoryBytes: processInfo.physicalMemory,
+            availableMemoryBytes: availableMemoryBytes(),
+            availableDiskBytes: availableDiskBytes(volumeURL: volumeURL),
+            osVersion: processInfo.operatingSystemVersionString
+        )
+    }
+
+    private func availableDiskBytes(volumeURL: URL) -> Int64? {
+        let values = try? volumeURL.resourceValues(forKeys: [.volumeAvailableCapacityForImportantUsageKey])
+        return values?.volumeAvailableCapacityForImportantUsage
+    }
+
+    private func sysctlString(_ name: String) -> String? {
+#if canImport(Darwin)
+        var size = 0
+        guard sysctlbyname(name, nil, &size, nil, 0) == 0, size > 0 else { return nil }
+        var buffer = [CChar](repeating: 0, count: size)
+        guard sysctlbyname(name, &buffer, &size, nil, 0) == 0 else { return nil }
+        return String(cString: buffer).trimmingCharacters(in: .whitespacesAndNewlines)
+#else
+        return nil
+#endif
+    }
+
+    private func sysctlInt(_ name: String) -> Int? {
+#if canImport(Darwin)
+        var value: Int32 = 0
+        var size = MemoryLayout<Int32>.size
+        guard sysctlbyname(name, &value, &size, nil, 0) == 0 else { return nil }
+        return Int(value)
+#else
+        return nil
+#endif
+    }
+
+    private func availableMemoryBytes() -> UInt64? {
+#if canImport(Darwin)
+        var stats = vm_statistics64_data_t()
+        var count = mach_msg_type_number_t(
+            MemoryLayout<vm_statistics64_data_t>.stride / MemoryLayout<integer_t>.stride
+        )
+        let host = mach_host_self()
+        let result = withUnsafeMutablePointer(to: &stats) { pointer in
+            pointer.withMemoryRebound(to: integer_t.self, capacity: Int(count)) {
+                host_statistics64(host, HOST_VM_INFO64, $0, &count)
+            }
+        }
+        guard result == KERN_SUCCESS else { return nil }
+
+        var pageSize: vm_size_t = 0
+        guard host_page_size(host, &pageSize) == KERN_SUCCESS else { return nil }
+
+        // Inactive and purgeable pages are reclaimed on demand, so count them as available.
+        let pages = UInt64(stats.free_count) + UInt64(stats.inactive_count) + UInt64(stats.purgeable_count)
+        return pages * UInt64(pageSize)
+#else
+        return nil
+#endif
+    }
+}
@@ -3,46 +3,247 @@ import Foundation
 import SecurityKit
 import TelemetryKit
 
-public actor DefaultModelManager: ModelManaging {
+public actor DefaultModelManager: ModelManaging, DownloadSessionReplaceable, DownloadThrottling {
     private let catalog: ModelCatalogProviding
     private let validator: ModelArtifactValidating
+    private let systemProfiler: SystemProfiling
     private let fileManager: FileManager
     private let installRoot: URL
+    private var session: URLSession
+    private var maxDownloadBytesPerSecond: Int?
+    private let downloadBufferSize: Int
+    private let networkStatus: (any NetworkStatusProviding)?
     private let telemetry: Telemetry
 
     public init(
         catalog: ModelCatalogProviding,
         validator: ModelArtifactValidating,
         installRoot: URL,
+        systemProfiler: SystemProfiling = DarwinSystemProfiler(),
         fileManager: FileManager = .default,
+        session: URLSession = .shared,
+        maxDownloadBytesPerSecond: Int? = nil,
+        downloadBufferSize: Int = 1 << 20,
+        networkStatus: (any NetworkStatusProviding)? = nil,
         telemetry: Telemetry = ConsoleTelemetry()
     ) {
         self.catalog = catalog
         self.validator = validator
         self.installRoot = installRoot
+        self.systemProfiler = systemProfiler
         self.fileManager = fileManager
+        self.session = session
+        self.maxDownloadBytesPerSecond = maxDownloadBytesPerSecond.map { max(1, $0) }
+        self.downloadBufferSize = max(1, downloadBufferSize)
+        self.networkStatus = networkStatus
         self.telemetry = telemetry
     }
 
-    public func install(modelID: String) async throws -> URL {
-        let manifest = try await catalog.fetchManifest()
-        guard let descriptor = manifest.first(where: { $0.modelID == modelID }) else {
-            throw FilesMindError.notFound("Model not found: \(modelID)")
+    public func preflight(modelID: String) async throws -> ModelPreflightReport {
+        let descriptor = try await resolveDescriptor(for: modelID)
+        try fileManager.createDirectory(at: installRoot, withIntermediateDirectories: true)
+
+        let profile = try await systemProfiler.currentProfile(volumeURL: installRoot)
+        let report = ModelPreflightEvaluator.evaluate(descriptor: descriptor, profile: profile)
+        if !report.meetsRequirements {
+            telemetry.warning("Model preflight failed: \(modelID), issues=\(report.issues.count)")
         }
+        return report
+    }
+
+    /// Interrupted downloads leave `model.safetensors.partial` behind and the next install resumes it. With a
+    /// network status provider, installs wait while offline and resume by themselves when the connection returns.
+    public func install(
+        modelID: String,
+        progress: (@Sendable (ModelDownloadProgress) -> Void)?
+    ) async throws -> URL {
+        let descriptor = try await resolveDescriptor(for: modelID)
 
         let modelDir = installRoot.appendingPathComponent(descriptor.modelID, isDirectory: true)
         try fileManager.createDirectory(at: modelDir, withIntermediateDirectories: true)
 
-        let destination = modelDir.appendingPathComponent("model.safetensors", isDirectory: false)
-        let (tmpURL, _) = try await URLSession.shared.download(from: descriptor.remoteURL)
+        let destination = modelDir.appendingPathComponent(Self.artifactFileName, isDirectory: false)
+        let partialURL = modelDir.appendingPathComponent("\(Self.artifactFileName).partial", isDirectory: false)
+        while true {
+            try await waitUntilOnline(modelID: descriptor.modelID)
+            do {
+                try await download(descriptor, to: partialURL, progress: progress)
+                break
+            } catch let error as URLError where Self.isConnectivityLoss(error) {
+                // Only wait it out if the system agrees we're offline; otherwise the failure is the server's.
+                guard let networkStatus, !(await networkStatus.currentStatus().isOnline) else {
+                    throw error
+                }
+                telemetry.warning("Model download paused, network offline: \(descriptor.modelID)")
+            }
+        }
+
+        do {
+            try await validator.validateArtifact(at: partialURL, expectedSHA256: descriptor.sha256)
+        } catch {
+            // A corrupt partial would fail the same way on resume, so start over next time.
+            try? fileManager.removeItem(at: partialURL)
+            throw error
+        }
 
         if fileManager.fileExists(atPath: destination.path) {
             try fileManager.removeItem(at: destination)
         }
-        try fileManager.moveItem(at: tmpURL, to: destination)
+        try fileManager.moveItem(at: partialURL, to: destination)
 
-        try await validator.validateArtifact(at: destination, expectedSHA256: descriptor.sha256)
         telemetry.info("Model installed: \(descriptor.modelID)")
         return destination
     }
+
+    public func replaceDownloadSession(_ session: URLSession) {
+        let previous = self.session
+        self.session = session
+        if previous !== URLSession.shared {
+            previous.finishTasksAndInvalidate()
+        }
+    }
+
+    /// Takes effect at the next buffer flush, so a download in progress picks up the new limit too.
+    public func setMaxDownloadBytesPerSecond(_ limit: Int?) {
+        maxDownloadBytesPerSecond = limit.map { max(1, $0) }
+    }
+
+    public func installedModels() async throws -> [InstalledModel] {
+        guard fileManager.fileExists(atPath: installRoot.path) else { return [] }
+
+        let directories = try fileManager.contentsOfDirectory(
+            at: installRoot,
+            includingPropertiesForKeys: [.isDirectoryKey],
+            options: [.skipsHiddenFiles]
+        )
+        return directories
+            .compactMap { directory -> InstalledModel? in
+                let artifact = directory.appendingPathComponent(Self.artifactFileName, isDirectory: false)
+                guard let values = try? artifact.resourceValues(forKeys: [.fileSizeKey, .isRegularFileKey]),
+                      values.isRegularFile == true
+                else {
+                    return nil
+                }
+                return InstalledModel(
+                    modelID: directory.lastPathComponent,
+                    fileURL: artifact,
+                    bytes: Int64(values.fileSize ?? 0)
+                )
+            }
+            .sorted(by: { $0.modelID < $1.modelID })
+    }
+
+    private static let artifactFileName = "model.safetensors"
+
+    private static func isConnectivityLoss(_ error: URLError) -> Bool {
+        switch error.code {
+        case .notConnectedToInternet, .networkConnectionLost, .dataNotAllowed, .cannotFindHost, .timedOut:
+            return true
+        default:
+            return false
+        }
+    }
+
+    private func waitUntilOnline(modelID: String) async throws {
+        guard let networkStatus, !(await networkStatus.currentStatus().isOnline) else { return }
+
+        telemetry.info("Model download waiting for network: \(modelID)")
+        for await status in await networkStatus.statusUpdates() where status.isOnline {
+            return
+        }
+        try Task.checkCancellation()
+    }
+
+    private func download(
+        _ descriptor: ModelDescriptor,
+        to partialURL: URL,
+        progress: (@Sendable (ModelDownloadProgress) -> Void)?
+    ) async throws {
+        if !fileManager.fileExists(atPath: partialURL.path) {
+            fileManager.createFile(atPath: partialURL.path, contents: nil)
+        }
+        let handle = try FileHandle(forWritingTo: partialURL)
+        defer { try? handle.close() }
+
+        var offset = Int64(try handle.seekToEnd())
+        var request = URLRequest(url: descriptor.remoteURL)
+        if offset > 0 {
+            request.setValue("bytes=\(offset)-", forHTTPHeaderField: "Range")
+        }
+
+        let reader = DownloadChunkReader(bufferLimit: 4 * downloadBufferSize)
+        defer { reader.cancel() }
+        let response = try await reader.start(request, in: session)
+        let statusCode = (response as? HTTPURLResponse)?.statusCode ?? 200
+        switch statusCode {
+        case 206:
+            telemetry.info("Resuming model download: \(descriptor.modelID), offset=\(offset)")
+        case 200:
+            // The server ignored the range request; discard what we have and take the full body.
+            if offset > 0 {
+                try handle.truncate(atOffset: 0)
+                offset = 0
+            }
+        case 416:
+            // Nothing left to fetch; the checksum decides whether the partial is usable.
+            return
+        default:
+            throw FilesMindError.invalidState("Model download failed with HTTP \(statusCode)")
+        }
+
+        let expectedLength = response.expectedContentLength
+        let totalBytes = expectedLength > 0 ? offset + expectedLength : (descriptor.bytes > 0 ? descriptor.bytes : nil)
+        let startedAt = Date()
+        var received: Int64 = 0
+        var buffer = Data()
+        buffer.reserveCapacity(downloadBufferSize)
+        // Pacing restarts when the limit changes, so lowering it mid-download doesn't stall for the bytes already in.
+        var throttle = (limit: maxDownloadBytesPerSecond, since: startedAt, bytes: Int64(0))
+
+        func flush() async throws {
+            guard !buffer.isEmpty else { return }
+            try Task.checkCancellation()
+            try handle.write(contentsOf: buffer)
+            received += Int64(buffer.count)
+            if throttle.limit != maxDownloadBytesPerSecond {
+                throttle = (limit: maxDownloadBytesPerSecond, since: Date(), bytes: 0)
+            }
+            throttle.bytes += Int64(buffer.count)
+            buffer.removeAll(keepingCapacity: true)
+
+            if let limit = throttle.limit {
+                let allowedElapsed = Double(throttle.bytes) / Double(limit)
+                let throttledElapsed = Date().timeIntervalSince(throttle.since)
+                if allowedElapsed > throttledElapsed {
+                    try await Task.sleep(for: .seconds(allowedElapsed - throttledElapsed))
+                }
+            }
+            let elapsed = Date().timeIntervalSince(startedAt)
+
+            progress?(
+                ModelDownloadProgress(
+                    modelID: descriptor.modelID,
+                    receivedBytes: offset + received,
+                    totalBytes: totalBytes,
+                    bytesPerSecond: elapsed > 0 ? Double(received) / elapsed : 0
+                )
+            )
+        }
+
+        while let chunk = try await reader.next() {
+            buffer.append(chunk)
+            if buffer.count >= downloadBufferSize {
+                try await flush()
+            }
+        }
+        try await flush()
+    }
+
+    private func resolveDescriptor(for modelID: String) async throws -> ModelDescriptor {
+        let manifest = try await catalog.fetchManifest()
+        guard let descriptor = manifest.first(where: { $0.modelID == modelID }) else {
+            throw FilesMindError.notFound("Model not found: \(modelID)")
+        }
+        return descriptor
+    }
 }
@@ -0,0 +1,134 @@
+import Foundation
+
+/// Reads a data task's body in the chunks URLSession delivers. `URLSession.AsyncBytes` hands a body over one byte
+/// per `next()`, which dominates the cost of a multi-gigabyte download. While more than `bufferLimit` bytes wait to
+/// be read the task is suspended, so a throttled reader doesn't pull the whole file into memory.
+final class DownloadChunkReader: NSObject, URLSessionDataDelegate, @unchecked Sendable {
+    private let lock = NSLock()
+    private let bufferLimit: Int
+    private var task: URLSessionDataTask?
+    private var responseWaiter: CheckedContinuation<URLResponse, Error>?
+    private var chunkWaiter: CheckedContinuation<Data?, Error>?
+    private var chunks: [Data] = []
+    private var bufferedBytes = 0
+    private var isSuspended = false
+    private var receivedResponse = false
+    private var completionError: Error?
+    private var isComplete = false
+
+    init(bufferLimit: Int) {
+        self.bufferLimit = max(1, bufferLimit)
+    }
+
+    /// Starts the request and returns once the response headers arrive.
+    func start(_ request: URLRequest, in session: URLSession) async throws -> URLResponse {
+        let task = session.dataTask(with: request)
+        task.delegate = self
+        lock.withLock { self.task = task }
+
+        return try await withTaskCancellationHandler {
+            try await withCheckedThrowingContinuation { continuation in
+                lock.withLock { responseWaiter = continuation }
+                task.resume()
+            }
+        } onCancel: {
+            task.cancel()
+        }
+    }
+
+    /// The next chunk of the body, or nil once it is complete.
+    func next() async throws -> Data? {
+        try await withTaskCancellationHandler {
+            try await withCheckedThrowingContinuation { continuation in
+                lock.lock()
+                if !chunks.isEmpty {
+                    let chunk = chunks.removeFirst()
+                    bufferedBytes -= chunk.count
+                    resumeIfDrained()
+                    lock.unlock()
+                    continuation.resume(returning: chunk)
+                } else if isComplete {
+                    let error = completionError
+                    lock.unlock()
+                    if let error {
+                        continuation.resume(throwing: error)
+                    } else {
+                        continuation.resume(returning: nil)
+                    }
+                } else {
+                    chunkWaiter = continuation
+                    resumeIfDrained()
+                    lock.unlock()
+                }
+            }
+        } onCancel: {
+            cancel()
+        }
+    }
+
+    /// Stops the transfer, e.g. when the caller gives up on the body early. Does nothing once it has finished.
+    func cancel() {
+        lock.withLock { task }?.cancel()
+    }
+
+    func urlSession(
+        _ session: URLSession,
+        dataTask: URLSessionDataTask,
+        didReceive response: URLResponse
+    ) async -> URLSession.ResponseDisposition {
+        let waiter = lock.withLock {
+            receivedResponse = true
+            defer { responseWaiter = nil }
+            return responseWaiter
+        }
+        waiter?.resume(returning: response)
+        return .allow
+    }
+
+    func urlSession(_ session: URLSession, dataTask: URLSessionDataTask, didReceive data: Data) {
+        let waiter = lock.withLock { () -> CheckedContinuation<Data?, Error>? in
+            if let chunkWaiter {
+                self.chunkWaiter = nil
+                return chunkWaiter
+            }
+            chunks.append(data)
+            bufferedBytes += data.count
+            if bufferedBytes >= bufferLimit, !isSuspended {
+                isSuspended = true
+                dataTask.suspend()
+            }
+            return nil
+        }
+        waiter?.resume(returning: data)
+    }
+
+    func urlSession(_ session: URLSession, task: URLSessionTask, didCompleteWithError error: Error?) {
+        let (responseWaiter, chunkWaiter, receivedResponse) = lock.withLock {
+            isComplete = true
+            completionError = error
+            self.task = nil
+            defer {
+                self.responseWaiter = nil
+                self.chunkWaiter = nil
+            }
+            return (self.responseWaiter, self.chunkWaiter, self.receivedResponse)
+        }
+
+        if let error {
+            responseWaiter?.resume(throwing: error)
+            chunkWaiter?.resume(throwing: error)
+        } else {
+            if !receivedResponse {
+                responseWaiter?.resume(throwing: URLError(.badServerResponse))
+            }
+            chunkWaiter?.resume(returning: nil)
+        }
+    }
+
+    // Callers hold `lock`.
+    private func resumeIfDrained() {
+        guard isSuspended, bufferedBytes <= bufferLimit / 2 else { return }
+        isSuspended = false
+        task?.resume()
+    }
+}
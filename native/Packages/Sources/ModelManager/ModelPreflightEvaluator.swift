@@ -0,0 +1,32 @@
+import Domain
+import Foundation
+
+public enum ModelPreflightEvaluator {
+    public static func evaluate(descriptor: ModelDescriptor, profile: SystemProfile) -> ModelPreflightReport {
+        var issues: [ModelPreflightIssue] = []
+
+        if descriptor.minimumMemoryBytes > 0 {
+            if profile.physicalMemoryBytes < UInt64(descriptor.minimumMemoryBytes) {
+                issues.append(
+                    .insufficientMemory(
+                        requiredBytes: descriptor.minimumMemoryBytes,
+                        physicalBytes: profile.physicalMemoryBytes
+                    )
+                )
+            } else if let available = profile.availableMemoryBytes, available < UInt64(descriptor.minimumMemoryBytes) {
+                issues.append(
+                    .lowAvailableMemory(
+                        requiredBytes: descriptor.minimumMemoryBytes,
+                        availableBytes: available
+                    )
+                )
+            }
+        }
+
+        if let availableDisk = profile.availableDiskBytes, availableDisk < descriptor.bytes {
+            issues.append(.insufficientDisk(requiredBytes: descriptor.bytes, availableBytes: availableDisk))
+        }
+
+        return ModelPreflightReport(modelID: descriptor.modelID, profile: profile, issues: issues)
+    }
+}
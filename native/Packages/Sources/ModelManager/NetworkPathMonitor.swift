@@ -0,0 +1,67 @@
+import Domain
+import Foundation
+#if canImport(Network)
+import Network
+#endif
+import TelemetryKit
+
+public actor NetworkPathMonitor: NetworkStatusProviding {
+    // Assume online until the first path update so startup work isn't held back.
+    private var status = NetworkStatus(isOnline: true)
+    private var continuations: [UUID: AsyncStream<NetworkStatus>.Continuation] = [:]
+    private let telemetry: Telemetry
+#if canImport(Network)
+    private let monitor = NWPathMonitor()
+#endif
+
+    public init(telemetry: Telemetry = ConsoleTelemetry()) {
+        self.telemetry = telemetry
+#if canImport(Network)
+        monitor.pathUpdateHandler = { [weak self] path in
+            let status = NetworkStatus(
+                isOnline: path.status == .satisfied,
+                isExpensive: path.isExpensive,
+                isConstrained: path.isConstrained
+            )
+            Task { await self?.update(status) }
+        }
+        monitor.start(queue: DispatchQueue(label: "filesmind.network-path-monitor", qos: .utility))
+#endif
+    }
+
+    deinit {
+#if canImport(Network)
+        monitor.cancel()
+#endif
+    }
+
+    public func currentStatus() -> NetworkStatus {
+        status
+    }
+
+    public func statusUpdates() -> AsyncStream<NetworkStatus> {
+        let id = UUID()
+        return AsyncStream { continuation in
+            continuations[id] = continuation
+            continuation.onTermination = { [weak self] _ in
+                Task { await self?.removeContinuation(id) }
+            }
+            continuation.yield(status)
+        }
+    }
+
+    private func update(_ newStatus: NetworkStatus) {
+        guard newStatus != status else { return }
+        status = newStatus
+        telemetry.info(
+            "Network status changed: online=\(newStatus.isOnline), expensive=\(newStatus.isExpensive), constrained=\(newStatus.isConstrained)"
+        )
+        for continuation in continuations.values {
+            continuation.yield(newStatus)
+        }
+    }
+
+    private func removeContinuation(_ id: UUID) {
+        continuations[id] = nil
+    }
+}
@@ -1,14 +1,16 @@
-import CryptoKit
 import Domain
 import Foundation
+import SecurityKit
 
 public struct SHA256ArtifactValidator: ModelArtifactValidating {
-    public init() {}
+    private let hasher: FileHasher
+
+    public init(hasher: FileHasher = FileHasher()) {
+        self.hasher = hasher
+    }
 
     public func validateArtifact(at url: URL, expectedSHA256: String) async throws {
-        let data = try Data(contentsOf: url)
-        let digest = SHA256.hash(data: data)
-        let checksum = digest.map { String(format: "%02x", $0) }.joined()
+        let checksum = try hasher.sha256(of: url)
 
         if checksum.lowercased() != expectedSHA256.lowercased() {
             throw FilesMindError.validationFailed("Artifact checksum mismatch")
@@ -0,0 +1,162 @@
+import Domain
+import Foundation
+import TelemetryKit
+
+public enum SearchExportFormat: String, Sendable, CaseIterable {
+    case csv
+    case jsonl
+
+    public var fileExtension: String { rawValue }
+}
+
+public struct SearchExportProgress: Sendable, Equatable {
+    public let writtenRows: Int
+    public let totalRows: Int
+
+    public init(writtenRows: Int, totalRows: Int) {
+        self.writtenRows = writtenRows
+        self.totalRows = totalRows
+    }
+
+    public var fractionCompleted: Double {
+        totalRows == 0 ? 1 : Double(writtenRows) / Double(totalRows)
+    }
+}
+
+public struct SearchResultExporter: Sendable {
+    private let batchSize: Int
+    private let telemetry: Telemetry
+
+    public init(batchSize: Int = 500, telemetry: Telemetry = ConsoleTelemetry()) {
+        self.batchSize = max(1, batchSize)
+        self.telemetry = telemetry
+    }
+
+    /// Rows are flushed to a sibling temp file in batches and moved into place once complete,
+    /// so a cancelled or failed export never leaves a truncated file at `destination`.
+    @discardableResult
+    public func export(
+        _ results: [RankedChunk],
+        documents: [UUID: ImportedDocumentRecord] = [:],
+        format: SearchExportFormat,
+        to destination: URL,
+        progress: (@Sendable (SearchExportProgress) -> Void)? = nil
+    ) async throws -> Int {
+        let fileManager = FileManager.default
+        let tempURL = destination.deletingLastPathComponent()
+            .appendingPathComponent(".\(destination.lastPathComponent).\(UUID().uuidString).partial", isDirectory: false)
+        guard fileManager.createFile(atPath: tempURL.path, contents: nil) else {
+            throw FilesMindError.unauthorized("Cannot write to \(destination.deletingLastPathComponent().path)")
+        }
+
+        do {
+            let handle = try FileHandle(forWritingTo: tempURL)
+            defer { try? handle.close() }
+
+            let encoder = JSONEncoder()
+            encoder.outputFormatting = [.sortedKeys, .withoutEscapingSlashes]
+
+            var buffer = Data()
+            if format == .csv {
+                buffer.append(Data(Self.csvHeader.utf8))
+            }
+
+            for (index, ranked) in results.enumerated() {
+                let row = ExportRow(rank: index + 1, ranked: ranked, document: documents[ranked.chunk.documentID])
+                switch format {
+                case .csv:
+                    buffer.append(Data(row.csvLine.utf8))
+                case .jsonl:
+                    buffer.append(try encoder.encode(row))
+                    buffer.append(0x0A)
+                }
+
+                let written = index + 1
+                if written % batchSize == 0 {
+                    try Task.checkCancellation()
+                    try handle.write(contentsOf: buffer)
+                    buffer.removeAll(keepingCapacity: true)
+                    progress?(SearchExportProgress(writtenRows: written, totalRows: results.count))
+                }
+            }
+
+            try handle.write(contentsOf: buffer)
+            try handle.synchronize()
+
+            if fileManager.fileExists(atPath: destination.path) {
+                _ = try fileManager.replaceItemAt(destination, withItemAt: tempURL)
+            } else {
+                try fileManager.moveItem(at: tempURL, to: destination)
+            }
+        } catch {
+            try? fileManager.removeItem(at: tempURL)
+            throw error
+        }
+
+        progress?(SearchExportProgress(writtenRows: results.count, totalRows: results.count))
+        telemetry.info("Exported search results. format=\(format.rawValue) rows=\(results.count)")
+        return results.count
+    }
+
+    static let csvHeader = "rank,score,document_id,document_title,source_path,chunk_id,ordinal,page,text\n"
+}
+
+private struct ExportRow: Encodable {
+    let rank: Int
+    let score: Double
+    let documentID: UUID
+    let documentTitle: String?
+    let sourcePath: String?
+    let chunkID: UUID
+    let ordinal: Int
+    let page: Int?
+    let text: String
+
+    init(rank: Int, ranked: RankedChunk, document: ImportedDocumentRecord?) {
+        self.rank = rank
+        self.score = ranked.score
+        self.documentID = ranked.chunk.documentID
+        self.documentTitle = document?.title
+        self.sourcePath = document?.sourcePath
+        self.chunkID = ranked.chunk.id
+        self.ordinal = ranked.chunk.ordinal
+        // Pages are stored zero-based; exports use the page numbers users see.
+        self.page = ranked.chunk.sourcePageIndex.map { $0 + 1 }
+        self.text = ranked.chunk.text
+    }
+
+    enum CodingKeys: String, CodingKey {
+        case rank
+        case score
+        case documentID = "document_id"
+        case documentTitle = "document_title"
+        case sourcePath = "source_path"
+        case chunkID = "chunk_id"
+        case ordinal
+        case page
+        case text
+    }
+
+    var csvLine: String {
+        [
+            String(rank),
+            String(score),
+            documentID.uuidString,
+            documentTitle ?? "",
+            sourcePath ?? "",
+            chunkID.uuidString,
+            String(ordinal),
+            page.map(String.init) ?? "",
+            text
+        ]
+        .map(Self.escapeCSV)
+        .joined(separator: ",") + "\n"
+    }
+
+    private static func escapeCSV(_ field: String) -> String {
+        guard field.contains(where: { $0 == "," || $0 == "\"" || $0 == "\n" || $0 == "\r" }) else {
+            return field
+        }
+        return "\"" + field.replacingOccurrences(of: "\"", with: "\"\"") + "\""
+    }
+}
@@ -0,0 +1,132 @@
+import Domain
+import Foundation
+import TelemetryKit
+#if canImport(Security)
+import Security
+#endif
+
+public actor DocumentPasswordVault: DocumentPasswordStoring {
+    private var sessionPasswords: [String: String] = [:]
+    private let service: String
+    private let usesKeychain: Bool
+    private let telemetry: Telemetry
+
+    public init(
+        service: String = "filesmind.document.password",
+        usesKeychain: Bool = true,
+        telemetry: Telemetry = ConsoleTelemetry()
+    ) {
+        self.service = service
+        self.usesKeychain = usesKeychain
+        self.telemetry = telemetry
+    }
+
+    public func password(for fileURL: URL) async throws -> String? {
+        let account = Self.account(for: fileURL)
+        if let password = sessionPasswords[account] {
+            return password
+        }
+        guard usesKeychain else { return nil }
+        return try readKeychainPassword(account: account)
+    }
+
+    /// Session passwords live until the app quits; persisted ones are also written to the keychain.
+    public func setPassword(_ password: String, for fileURL: URL, persist: Bool) async throws {
+        let account = Self.account(for: fileURL)
+        sessionPasswords[account] = password
+        if persist && usesKeychain {
+            try writeKeychainPassword(password, account: account)
+            telemetry.info("Stored document password in keychain: \(fileURL.lastPathComponent)")
+        }
+    }
+
+    public func removePassword(for fileURL: URL) async throws {
+        let account = Self.account(for: fileURL)
+        sessionPasswords[account] = nil
+        if usesKeychain {
+            try deleteKeychainPassword(account: account)
+        }
+    }
+
+    /// Forgets session passwords and deletes every keychain item this vault's service saved.
+    public func removeAllPasswords() async throws {
+        sessionPasswords.removeAll()
+        if usesKeychain {
+            try deleteAllKeychainPasswords()
+        }
+    }
+
+    private static func account(for fileURL: URL) -> String {
+        fileURL.standardizedFileURL.path
+    }
+
+#if canImport(Security)
+    private func keychainQuery(account: String) -> [String: Any] {
+        [
+            kSecClass as String: kSecClassGenericPassword,
+            kSecAttrService as String: service,
+            kSecAttrAccount as String: account
+        ]
+    }
+
+    private func readKeychainPassword(account: String) throws -> String? {
+        var query = keychainQuery(account: account)
+        query[kSecReturnData as String] = true
+        query[kSecMatchLimit as String] = kSecMatchLimitOne
+
+        var item: CFTypeRef?
+        let status = SecItemCopyMatching(query as CFDictionary, &item)
+        if status == errSecItemNotFound {
+            return nil
+        }
+        guard status == errSecSuccess, let data = item as? Data else {
+            throw FilesMindError.invalidState("Keychain read failed with status \(status)")
+        }
+        return String(data: data, encoding: .utf8)
+    }
+
+    private func writeKeychainPassword(_ password: String, account: String) throws {
+        try deleteKeychainPassword(account: account)
+
+        var attributes = keychainQuery(account: account)
+        attributes[kSecValueData as String] = Data(password.utf8)
+        attributes[kSecAttrAccessible as String] = kSecAttrAccessibleWhenUnlocked
+
+        let status = SecItemAdd(attributes as CFDictionary, nil)
+        guard status == errSecSuccess else {
+            throw FilesMindError.invalidState("Keychain write failed with status \(status)")
+        }
+    }
+
+    private func deleteKeychainPassword(account: String) throws {
+        let status = SecItemDelete(keychainQuery(account: account) as CFDictionary)
+        guard status == errSecSuccess || status == errSecItemNotFound else {
+            throw FilesMindError.invalidState("Keychain delete failed with status \(status)")
+        }
+    }
+
+    private func deleteAllKeychainPasswords() throws {
+        // Without an account attribute the query matches, and deletes, every password of the service.
+        let query: [String: Any] = [
+            kSecClass as String: kSecClassGenericPassword,
+            kSecAttrService as String: service
+        ]
+        let status = SecItemDelete(query as CFDictionary)
+        guard status == errSecSuccess || status == errSecItemNotFound else {
+            throw FilesMindError.invalidState("Keychain delete failed with status \(status)")
+        }
+    }
+#else
+    private func readKeychainPassword(account: String) throws -> String? {
+        nil
+    }
+
+    private func writeKeychainPassword(_ password: String, account: String) throws {
+        throw FilesMindError.notSupported("Keychain not available on this build")
+    }
+
+    private func deleteKeychainPassword(account: String) throws {}
+
+    private func deleteAllKeychainPasswords() throws {}
+#endif
+}
@@ -0,0 +1,23 @@
+import CryptoKit
+import Foundation
+
+/// SHA-256 over file contents, read in fixed-size blocks so multi-gigabyte files never load into memory at once.
+public struct FileHasher: Sendable {
+    private let bufferSize: Int
+
+    public init(bufferSize: Int = 1 << 20) {
+        self.bufferSize = max(1, bufferSize)
+    }
+
+    public func sha256(of fileURL: URL) throws -> String {
+        let handle = try FileHandle(forReadingFrom: fileURL)
+        defer { try? handle.close() }
+
+        var hasher = SHA256()
+        while let block = try handle.read(upToCount: bufferSize), !block.isEmpty {
+            try Task.checkCancellation()
+            hasher.update(data: block)
+        }
+        return hasher.finalize().map { String(format: "%02x", $0) }.joined()
+    }
+}
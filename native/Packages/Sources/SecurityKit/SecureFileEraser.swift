@@ -0,0 +1,37 @@
+import Domain
+import Foundation
+
+/// Overwrites a file's contents with zeros in fixed-size blocks, flushes them to disk, then deletes it. This is best
+/// effort: APFS snapshots, Time Machine and SSD wear levelling can keep earlier blocks around, so FileVault remains
+/// the real protection for confidential documents.
+public struct SecureFileEraser: Sendable {
+    private let bufferSize: Int
+
+    public init(bufferSize: Int = 1 << 20) {
+        self.bufferSize = max(1, bufferSize)
+    }
+
+    public func erase(_ fileURL: URL) throws {
+        let values = try fileURL.resourceValues(forKeys: [.isRegularFileKey, .fileSizeKey])
+        guard values.isRegularFile == true else {
+            throw FilesMindError.notSupported("Only regular files can be erased: \(fileURL.lastPathComponent)")
+        }
+
+        let handle = try FileHandle(forWritingTo: fileURL)
+        do {
+            let zeros = Data(count: bufferSize)
+            var remaining = values.fileSize ?? 0
+            while remaining > 0 {
+                let count = min(remaining, bufferSize)
+                try handle.write(contentsOf: count == bufferSize ? zeros : zeros.prefix(count))
+                remaining -= count
+            }
+            try handle.synchronize()
+            try handle.close()
+        } catch {
+            try? handle.close()
+            throw error
+        }
+        try FileManager.default.removeItem(at: fileURL)
+    }
+}
@@ -83,6 +83,12 @@ public actor SecurityScopedBookmarkManager: WorkspaceAuthorizationManaging {
         telemetry.info("Scoped access stopped for workspace: \(handle.workspaceID.rawValue)")
     }
 
+    /// Forgets the saved bookmark; access already started stays valid until it is stopped.
+    public func revokeAuthorization(id: WorkspaceID) async throws {
+        try await store.removeBookmark(for: id)
+        telemetry.info("Workspace authorization revoked: \(id.rawValue)")
+    }
+
     private func makeBookmarkData(for directoryURL: URL) throws -> Data {
 #if os(macOS)
         return try directoryURL.bookmarkData(
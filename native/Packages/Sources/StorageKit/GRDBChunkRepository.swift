@@ -3,69 +3,26 @@ import Foundation
 import GRDB
 import TelemetryKit
 
-public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, ImportedDocumentStore {
+public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, ImportedDocumentStore, DocumentRemoving, LibraryErasing, FailedImportStore, BackupCapableStore {
     private let dbQueue: DatabaseQueue
+    private let databaseURL: URL
     private let telemetry: Telemetry
 
     public init(databaseURL: URL, telemetry: Telemetry = ConsoleTelemetry()) throws {
+        self.databaseURL = databaseURL
         self.telemetry = telemetry
 
         let directory = databaseURL.deletingLastPathComponent()
         try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
 
-        self.dbQueue = try DatabaseQueue(path: databaseURL.path)
-
-        var migrator = DatabaseMigrator()
-        migrator.registerMigration("v1_create_chunks") { db in
-            try db.create(table: "chunks", ifNotExists: true) { table in
-                table.column("id", .text).primaryKey()
-                table.column("document_id", .text).notNull()
-                table.column("ordinal", .integer).notNull()
-                table.column("text", .text).notNull()
-                table.column("source_page_index", .integer)
-                table.column("created_at", .datetime).notNull().defaults(sql: "CURRENT_TIMESTAMP")
-                table.column("updated_at", .datetime).notNull().defaults(sql: "CURRENT_TIMESTAMP")
-            }
-
-            try db.create(
-                index: "idx_chunks_document_ordinal",
-                on: "chunks",
-                columns: ["document_id", "ordinal"],
-                ifNotExists: true
-            )
+        var configuration = Configuration()
+        // Deleted rows are zeroed instead of lingering in free pages, so removing a document removes its text.
+        configuration.prepareDatabase { db in
+            try db.execute(sql: "PRAGMA secure_delete = ON")
         }
+        self.dbQueue = try DatabaseQueue(path: databaseURL.path, configuration: configuration)
 
-        migrator.registerMigration("v2_create_documents") { db in
-            try db.create(table: "documents", ifNotExists: true) { table in
-                table.column("id", .text).primaryKey()
-                table.column("source_path", .text).notNull()
-                table.column("title", .text).notNull()
-                table.column("source_type", .text).notNull()
-                table.column("chunk_count", .integer).notNull()
-                table.column("low_quality_pages_json", .text).notNull()
-                table.column("imported_at", .datetime).notNull()
-                table.column("updated_at", .datetime).notNull().defaults(sql: "CURRENT_TIMESTAMP")
-            }
-
-            try db.create(table: "document_sections", ifNotExists: true) { table in
-                table.column("id", .text).primaryKey()
-                table.column("document_id", .text).notNull().indexed()
-                table.column("level", .integer).notNull()
-                table.column("title", .text).notNull()
-                table.column("chunk_start_ordinal", .integer).notNull()
-            }
-        }
-
-        migrator.registerMigration("v3_add_chunk_source_page_index") { db in
-            let columns = try db.columns(in: "chunks").map(\.name)
-            if !columns.contains("source_page_index") {
-                try db.alter(table: "chunks") { table in
-                    table.add(column: "source_page_index", .integer)
-                }
-            }
-        }
-
-        try migrator.migrate(dbQueue)
+        try Self.makeMigrator().migrate(dbQueue)
         telemetry.info("GRDB chunk repository initialized at: \(databaseURL.path)")
     }
 
@@ -76,12 +33,13 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
             for chunk in chunks {
                 try db.execute(
                     sql: """
-                    INSERT INTO chunks (id, document_id, ordinal, text, source_page_index, updated_at)
-                    VALUES (?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+                    INSERT INTO chunks (id, document_id, ordinal, text, search_text, source_page_index, updated_at)
+                    VALUES (?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
                     ON CONFLICT(id) DO UPDATE SET
                       document_id = excluded.document_id,
                       ordinal = excluded.ordinal,
                       text = excluded.text,
+                      search_text = excluded.search_text,
                       source_page_index = excluded.source_page_index,
                       updated_at = CURRENT_TIMESTAMP
                     """,
@@ -90,6 +48,7 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
                         chunk.documentID.uuidString,
                         chunk.ordinal,
                         chunk.text,
+                        SearchTextNormalizer.searchKey(chunk.text),
                         chunk.sourcePageIndex
                     ]
                 )
@@ -100,7 +59,7 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
     }
 
     public func search(byKeyword keyword: String, limit: Int) async throws -> [Chunk] {
-        let normalized = keyword.trimmingCharacters(in: .whitespacesAndNewlines).lowercased()
+        let normalized = SearchTextNormalizer.searchKey(keyword.trimmingCharacters(in: .whitespacesAndNewlines))
         guard !normalized.isEmpty else {
             return []
         }
@@ -111,7 +70,7 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
                 sql: """
                 SELECT id, document_id, ordinal, text, source_page_index
                 FROM chunks
-                WHERE lower(text) LIKE ?
+                WHERE search_text LIKE ?
                 ORDER BY ordinal ASC
                 LIMIT ?
                 """,
@@ -150,9 +109,10 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
             try db.execute(
                 sql: """
                 INSERT INTO documents (
-                  id, source_path, title, source_type, chunk_count, low_quality_pages_json, imported_at, updated_at
+                  id, source_path, title, source_type, chunk_count, low_quality_pages_json, imported_at, language_code,
+                  updated_at
                 )
-                VALUES (?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
                 ON CONFLICT(id) DO UPDATE SET
                   source_path = excluded.source_path,
                   title = excluded.title,
@@ -160,6 +120,7 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
                   chunk_count = excluded.chunk_count,
                   low_quality_pages_json = excluded.low_quality_pages_json,
                   imported_at = excluded.imported_at,
+                  language_code = excluded.language_code,
                   updated_at = CURRENT_TIMESTAMP
                 """,
                 arguments: [
@@ -169,7 +130,8 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
                     document.sourceType.rawValue,
                     document.chunkCount,
                     lowQualityPagesJSON,
-                    document.importedAt
+                    document.importedAt,
+                    document.languageCode
                 ]
             )
 
@@ -196,12 +158,91 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
         }
     }
 
+    public func removeDocument(id: UUID) async throws {
+        try await dbQueue.write { db in
+            let arguments: StatementArguments = [id.uuidString]
+            try db.execute(sql: "DELETE FROM chunks WHERE document_id = ?", arguments: arguments)
+            try db.execute(sql: "DELETE FROM document_sections WHERE document_id = ?", arguments: arguments)
+            try db.execute(sql: "DELETE FROM documents WHERE id = ?", arguments: arguments)
+        }
+        telemetry.info("Removed document from GRDB store: \(id.uuidString)")
+    }
+
+    /// Empties the database file in place, since it stays open, and recreates the schema. The pre-restore copy holds
+    /// an older library too, so it is deleted as well.
+    public func eraseLibrary() async throws {
+        try await dbQueue.erase()
+        try Self.makeMigrator().migrate(dbQueue)
+        if FileManager.default.fileExists(atPath: preRestoreURL.path) {
+            try FileManager.default.removeItem(at: preRestoreURL)
+        }
+        telemetry.info("Erased GRDB library at: \(databaseURL.path)")
+    }
+
+    public func writeBackup(to destinationURL: URL) async throws {
+        try FileManager.default.createDirectory(
+            at: destinationURL.deletingLastPathComponent(),
+            withIntermediateDirectories: true
+        )
+        let destination = try DatabaseQueue(path: destinationURL.path)
+        try dbQueue.backup(to: destination)
+        try destination.close()
+        telemetry.info("Wrote GRDB backup: \(destinationURL.lastPathComponent)")
+    }
+
+    /// The current contents are copied to `<name>.pre-restore.sqlite` first and put back if the restore fails.
+    public func restoreBackup(from backupURL: URL) async throws {
+        let migrator = Self.makeMigrator()
+        var configuration = Configuration()
+        configuration.readonly = true
+        let source: DatabaseQueue
+        do {
+            source = try DatabaseQueue(path: backupURL.path, configuration: configuration)
+            try source.read { db in
+                guard try db.tableExists("chunks"), try db.tableExists("documents") else {
+                    throw FilesMindError.validationFailed("Not a FilesMind library backup")
+                }
+                guard try String.fetchOne(db, sql: "PRAGMA quick_check") == "ok" else {
+                    throw FilesMindError.validationFailed("Backup failed its integrity check")
+                }
+            }
+        } catch let error as FilesMindError {
+            throw error
+        } catch {
+            throw FilesMindError.validationFailed("Unreadable backup: \(error.localizedDescription)")
+        }
+        defer { try? source.close() }
+
+        let known = Set(migrator.migrations)
+        let applied = try source.read { db in try migrator.appliedIdentifiers(db) }
+        if let unknown = applied.subtracting(known).sorted().first {
+            throw FilesMindError.validationFailed("Backup comes from a newer FilesMind (migration \(unknown))")
+        }
+
+        let safetyURL = preRestoreURL
+        try? FileManager.default.removeItem(at: safetyURL)
+        try await writeBackup(to: safetyURL)
+
+        do {
+            try source.backup(to: dbQueue)
+            // Older backups are brought up to the current schema.
+            try migrator.migrate(dbQueue)
+        } catch {
+            telemetry.error("GRDB restore failed, rolling back: \(error.localizedDescription)")
+            let safety = try DatabaseQueue(path: safetyURL.path, configuration: configuration)
+            try safety.backup(to: dbQueue)
+            try safety.close()
+            throw error
+        }
+        telemetry.info("Restored GRDB store from backup: \(backupURL.lastPathComponent)")
+    }
+
     public func recentDocuments(limit: Int) async throws -> [ImportedDocumentRecord] {
         try await dbQueue.read { db in
             let rows = try Row.fetchAll(
                 db,
                 sql: """
-                SELECT id, source_path, title, source_type, chunk_count, low_quality_pages_json, imported_at
+                SELECT id, source_path, title, source_type, chunk_count, low_quality_pages_json, imported_at, language_code
                 FROM documents
                 ORDER BY imported_at DESC
                 LIMIT ?
@@ -228,6 +269,153 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
         }
     }
 
+    public func upsertFailedImport(_ record: FailedImportRecord) async throws {
+        try await dbQueue.write { db in
+            try db.execute(
+                sql: """
+                INSERT INTO failed_imports (id, file_path, priority, attempt_count, last_error, failed_at)
+                VALUES (?, ?, ?, ?, ?, ?)
+                ON CONFLICT(id) DO UPDATE SET
+                  file_path = excluded.file_path,
+                  priority = excluded.priority,
+                  attempt_count = excluded.attempt_count,
+                  last_error = excluded.last_error,
+                  failed_at = excluded.failed_at
+                """,
+                arguments: [
+                    record.id.uuidString,
+                    record.fileURL.path,
+                    record.priority.rawValue,
+                    record.attemptCount,
+                    record.lastError,
+                    record.failedAt
+                ]
+            )
+        }
+    }
+
+    public func failedImports() async throws -> [FailedImportRecord] {
+        try await dbQueue.read { db in
+            let rows = try Row.fetchAll(
+                db,
+                sql: """
+                SELECT id, file_path, priority, attempt_count, last_error, failed_at
+                FROM failed_imports
+                ORDER BY failed_at DESC
+                """
+            )
+            return rows.compactMap(Self.makeFailedImport(from:))
+        }
+    }
+
+    public func removeFailedImport(id: UUID) async throws {
+        try await dbQueue.write { db in
+            try db.execute(
+                sql: "DELETE FROM failed_imports WHERE id = ?",
+                arguments: [id.uuidString]
+            )
+        }
+    }
+
+    private var preRestoreURL: URL {
+        databaseURL
+            .deletingPathExtension()
+            .appendingPathExtension("pre-restore.sqlite")
+    }
+
+    private static func makeMigrator() -> DatabaseMigrator {
+        var migrator = DatabaseMigrator()
+        migrator.registerMigration("v1_create_chunks") { db in
+            try db.create(table: "chunks", ifNotExists: true) { table in
+                table.column("id", .text).primaryKey()
+                table.column("document_id", .text).notNull()
+                table.column("ordinal", .integer).notNull()
+                table.column("text", .text).notNull()
+                table.column("source_page_index", .integer)
+                table.column("created_at", .datetime).notNull().defaults(sql: "CURRENT_TIMESTAMP")
+                table.column("updated_at", .datetime).notNull().defaults(sql: "CURRENT_TIMESTAMP")
+            }
+
+            try db.create(
+                index: "idx_chunks_document_ordinal",
+                on: "chunks",
+                columns: ["document_id", "ordinal"],
+                ifNotExists: true
+            )
+        }
+
+        migrator.registerMigration("v2_create_documents") { db in
+            try db.create(table: "documents", ifNotExists: true) { table in
+                table.column("id", .text).primaryKey()
+                table.column("source_path", .text).notNull()
+                table.column("title", .text).notNull()
+                table.column("source_type", .text).notNull()
+                table.column("chunk_count", .integer).notNull()
+                table.column("low_quality_pages_json", .text).notNull()
+                table.column("imported_at", .datetime).notNull()
+                table.column("updated_at", .datetime).notNull().defaults(sql: "CURRENT_TIMESTAMP")
+            }
+
+            try db.create(table: "document_sections", ifNotExists: true) { table in
+                table.column("id", .text).primaryKey()
+                table.column("document_id", .text).notNull().indexed()
+                table.column("level", .integer).notNull()
+                table.column("title", .text).notNull()
+                table.column("chunk_start_ordinal", .integer).notNull()
+            }
+        }
+
+        migrator.registerMigration("v3_add_chunk_source_page_index") { db in
+            let columns = try db.columns(in: "chunks").map(\.name)
+            if !columns.contains("source_page_index") {
+                try db.alter(table: "chunks") { table in
+                    table.add(column: "source_page_index", .integer)
+                }
+            }
+        }
+
+        migrator.registerMigration("v4_create_failed_imports") { db in
+            try db.create(table: "failed_imports", ifNotExists: true) { table in
+                table.column("id", .text).primaryKey()
+                table.column("file_path", .text).notNull()
+                table.column("priority", .text).notNull()
+                table.column("attempt_count", .integer).notNull()
+                table.column("last_error", .text).notNull()
+                table.column("failed_at", .datetime).notNull()
+            }
+        }
+
+        migrator.registerMigration("v5_add_chunk_search_text") { db in
+            let columns = try db.columns(in: "chunks").map(\.name)
+            if !columns.contains("search_text") {
+                try db.alter(table: "chunks") { table in
+                    table.add(column: "search_text", .text)
+                }
+            }
+
+            let rows = try Row.fetchAll(db, sql: "SELECT id, text FROM chunks")
+            for row in rows {
+                let id: String = row["id"]
+                let text: String = row["text"]
+                try db.execute(
+                    sql: "UPDATE chunks SET search_text = ? WHERE id = ?",
+                    arguments: [SearchTextNormalizer.searchKey(text), id]
+                )
+            }
+        }
+
+        migrator.registerMigration("v6_add_document_language_code") { db in
+            let columns = try db.columns(in: "documents").map(\.name)
+            if !columns.contains("language_code") {
+                try db.alter(table: "documents") { table in
+                    table.add(column: "language_code", .text)
+                }
+            }
+        }
+
+        return migrator
+    }
+
     private static func makeChunk(from row: Row) -> Chunk? {
         guard
             let idString: String = row["id"],
@@ -283,6 +471,7 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
         let importedAt: Date = row["imported_at"]
         let lowQualityPagesJSONString: String = row["low_quality_pages_json"]
         let lowQualityPages = try decodeLowQualityPages(lowQualityPagesJSONString)
+        let languageCode: String? = row["language_code"]
 
         return ImportedDocumentRecord(
             id: id,
@@ -291,7 +480,33 @@ public actor GRDBChunkRepository: ChunkRepository, EmbeddingSearchRepository, Im
             sourceType: sourceType,
             chunkCount: chunkCount,
             lowQualityPages: lowQualityPages,
-            importedAt: importedAt
+            importedAt: importedAt,
+            languageCode: languageCode
+        )
+    }
+
+    private static func makeFailedImport(from row: Row) -> FailedImportRecord? {
+        guard
+            let idString: String = row["id"],
+            let id = UUID(uuidString: idString),
+            let priorityRaw: String = row["priority"],
+            let priority = ImportPriority(rawValue: priorityRaw)
+        else {
+            return nil
+        }
+
+        let filePath: String = row["file_path"]
+        let attemptCount: Int = row["attempt_count"]
+        let lastError: String = row["last_error"]
+        let failedAt: Date = row["failed_at"]
+
+        return FailedImportRecord(
+            id: id,
+            fileURL: URL(fileURLWithPath: filePath),
+            priority: priority,
+            attemptCount: attemptCount,
+            lastError: lastError,
+            failedAt: failedAt
         )
     }
 
@@ -2,10 +2,11 @@ import Domain
 import Foundation
 import TelemetryKit
 
-public actor InMemoryChunkRepository: ChunkRepository, EmbeddingSearchRepository, ImportedDocumentStore {
+public actor InMemoryChunkRepository: ChunkRepository, EmbeddingSearchRepository, ImportedDocumentStore, DocumentRemoving, LibraryErasing, FailedImportStore {
     private var chunksByID: [UUID: Chunk] = [:]
     private var documentsByID: [UUID: ImportedDocumentRecord] = [:]
     private var sectionsByDocumentID: [UUID: [ParsedSection]] = [:]
+    private var failedImportsByID: [UUID: FailedImportRecord] = [:]
     private let telemetry: Telemetry
 
     public init(telemetry: Telemetry = ConsoleTelemetry()) {
@@ -20,13 +21,13 @@ public actor InMemoryChunkRepository: ChunkRepository, EmbeddingSearchRepository
     }
 
     public func search(byKeyword keyword: String, limit: Int) async throws -> [Chunk] {
-        let normalized = keyword.trimmingCharacters(in: .whitespacesAndNewlines).lowercased()
+        let normalized = SearchTextNormalizer.searchKey(keyword.trimmingCharacters(in: .whitespacesAndNewlines))
         guard !normalized.isEmpty else {
             return []
         }
 
         return chunksByID.values
-            .filter { $0.text.lowercased().contains(normalized) }
+            .filter { SearchTextNormalizer.searchKey($0.text).contains(normalized) }
             .sorted(by: { $0.ordinal < $1.ordinal })
             .prefix(max(limit, 0))
             .map { $0 }
@@ -59,4 +60,29 @@ public actor InMemoryChunkRepository: ChunkRepository, EmbeddingSearchRepository
     public func sections(for documentID: UUID) async throws -> [ParsedSection] {
         sectionsByDocumentID[documentID] ?? []
     }
+
+    public func removeDocument(id: UUID) async throws {
+        documentsByID[id] = nil
+        sectionsByDocumentID[id] = nil
+        chunksByID = chunksByID.filter { $0.value.documentID != id }
+    }
+
+    public func eraseLibrary() async throws {
+        chunksByID.removeAll()
+        documentsByID.removeAll()
+        sectionsByDocumentID.removeAll()
+        failedImportsByID.removeAll()
+    }
+
+    public func upsertFailedImport(_ record: FailedImportRecord) async throws {
+        failedImportsByID[record.id] = record
+    }
+
+    public func failedImports() async throws -> [FailedImportRecord] {
+        failedImportsByID.values.sorted(by: { $0.failedAt > $1.failedAt })
+    }
+
+    public func removeFailedImport(id: UUID) async throws {
+        failedImportsByID[id] = nil
+    }
 }
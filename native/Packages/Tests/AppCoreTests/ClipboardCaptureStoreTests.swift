@@ -0,0 +1,29 @@
+import AppCore
+import Domain
+import Foundation
+import Testing
+
+@Test("ClipboardCaptureStore should write trimmed text to uniquely named markdown files")
+func clipboardCaptureWritesMarkdown() throws {
+    let directory = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-captures-\(UUID().uuidString)", isDirectory: true)
+    let store = ClipboardCaptureStore(directoryURL: directory)
+    let capturedAt = Date(timeIntervalSince1970: 1_700_000_000)
+
+    let first = try store.writeText("\n  Meeting notes\n- ship it\n\n", capturedAt: capturedAt)
+    let second = try store.writeText("Another clip", capturedAt: capturedAt)
+
+    #expect(first.pathExtension == "md")
+    #expect(first.lastPathComponent.hasPrefix("Clipboard "))
+    #expect(second.deletingPathExtension().lastPathComponent == first.deletingPathExtension().lastPathComponent + " 2")
+    #expect(try String(contentsOf: first, encoding: .utf8) == "Meeting notes\n- ship it\n")
+}
+
+@Test("ClipboardCaptureStore should reject whitespace-only text")
+func clipboardCaptureRejectsEmptyText() {
+    let store = ClipboardCaptureStore(directoryURL: FileManager.default.temporaryDirectory)
+
+    #expect(throws: FilesMindError.self) {
+        try store.writeText(" \n\t ")
+    }
+}
@@ -0,0 +1,126 @@
+import AppCore
+import Domain
+import Foundation
+import Testing
+
+@Test("DatabaseBackupScheduler should write timestamped backups and keep only the newest")
+func backupSchedulerPrunesOldArchives() async throws {
+    let destination = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-backups-\(UUID().uuidString)", isDirectory: true)
+    let clock = SteppingClock(start: Date(timeIntervalSince1970: 1_700_000_000), step: 3_600)
+    let scheduler = DatabaseBackupScheduler(store: FileCopyBackupStore(), now: { clock.next() })
+    let events = await scheduler.subscribe()
+    // With no archive yet, configuring takes the first backup right away.
+    await scheduler.configure(BackupConfiguration(interval: 24 * 60 * 60, destinationURL: destination, retainCount: 2))
+
+    var iterator = events.makeAsyncIterator()
+    guard case .completed(let first)? = await iterator.next() else {
+        Issue.record("Expected the scheduled backup to complete")
+        return
+    }
+    var written = [first]
+    for _ in 0..<2 {
+        written.append(try await scheduler.backupNow())
+    }
+
+    let kept = try await scheduler.backups()
+    #expect(kept.map(\.lastPathComponent) == [written[2], written[1]].map(\.lastPathComponent))
+    #expect(!FileManager.default.fileExists(atPath: written[0].path))
+    await scheduler.configure(nil)
+}
+
+@Test("DatabaseBackupScheduler should refuse to back up before it is configured")
+func backupSchedulerRequiresConfiguration() async {
+    let scheduler = DatabaseBackupScheduler(store: FileCopyBackupStore())
+
+    await #expect(throws: FilesMindError.self) {
+        try await scheduler.backupNow()
+    }
+}
+
+@Test("DatabaseBackupScheduler should schedule the next backup from the newest archive")
+func backupSchedulerResumesScheduleFromNewestArchive() async throws {
+    let destination = try makeBackupDirectory(withArchiveNamed: "FilesMind-20231114T221320Z.sqlite")
+    let archiveDate = Date(timeIntervalSince1970: 1_700_000_000)
+    let scheduler = DatabaseBackupScheduler(store: FileCopyBackupStore(), now: { archiveDate.addingTimeInterval(2 * 3_600) })
+    await scheduler.configure(BackupConfiguration(interval: 24 * 60 * 60, destinationURL: destination))
+
+    #expect(await scheduler.nextBackupDate() == archiveDate.addingTimeInterval(24 * 60 * 60))
+    try? await Task.sleep(for: .milliseconds(200))
+    #expect(try await scheduler.backups().count == 1)
+    await scheduler.configure(nil)
+}
+
+@Test("DatabaseBackupScheduler should back up right away when the newest archive is overdue")
+func backupSchedulerBacksUpImmediatelyWhenOverdue() async throws {
+    let destination = try makeBackupDirectory(withArchiveNamed: "FilesMind-20231114T221320Z.sqlite")
+    let archiveDate = Date(timeIntervalSince1970: 1_700_000_000)
+    let scheduler = DatabaseBackupScheduler(store: FileCopyBackupStore(), now: { archiveDate.addingTimeInterval(25 * 3_600) })
+    await scheduler.configure(BackupConfiguration(interval: 24 * 60 * 60, destinationURL: destination))
+
+    try? await Task.sleep(for: .milliseconds(200))
+    let kept = try await scheduler.backups()
+    #expect(kept.map(\.lastPathComponent) == ["FilesMind-20231115T231320Z.sqlite", "FilesMind-20231114T221320Z.sqlite"])
+    await scheduler.configure(nil)
+}
+
+@Test("DatabaseBackupScheduler should remove only its own archives and start the schedule over")
+func backupSchedulerRemovesAllBackups() async throws {
+    let destination = try makeBackupDirectory(withArchiveNamed: "FilesMind-20231114T221320Z.sqlite")
+    let now = Date(timeIntervalSince1970: 1_700_000_000).addingTimeInterval(2 * 3_600)
+    let scheduler = DatabaseBackupScheduler(store: FileCopyBackupStore(), now: { now })
+    await scheduler.configure(BackupConfiguration(interval: 24 * 60 * 60, destinationURL: destination))
+
+    let partialURL = destination.appendingPathComponent(".FilesMind-20231115T001320Z.sqlite.partial")
+    let unrelatedURL = destination.appendingPathComponent("Notes.txt")
+    try Data("partial".utf8).write(to: partialURL)
+    try Data("keep me".utf8).write(to: unrelatedURL)
+
+    try await scheduler.removeAllBackups()
+
+    #expect(!FileManager.default.fileExists(atPath: destination.appendingPathComponent("FilesMind-20231114T221320Z.sqlite").path))
+    #expect(!FileManager.default.fileExists(atPath: partialURL.path))
+    #expect(try String(contentsOf: unrelatedURL, encoding: .utf8) == "keep me")
+    // With its archives gone, the schedule takes a fresh backup right away.
+    try? await Task.sleep(for: .milliseconds(200))
+    #expect(try await scheduler.backups().map(\.lastPathComponent) == ["FilesMind-20231115T001320Z.sqlite"])
+    await scheduler.configure(nil)
+}
+
+private func makeBackupDirectory(withArchiveNamed name: String) throws -> URL {
+    let directory = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-backups-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+    try Data("snapshot".utf8).write(to: directory.appendingPathComponent(name))
+    return directory
+}
+
+private struct FileCopyBackupStore: BackupCapableStore {
+    func writeBackup(to destinationURL: URL) async throws {
+        try FileManager.default.createDirectory(
+            at: destinationURL.deletingLastPathComponent(),
+            withIntermediateDirectories: true
+        )
+        try Data("snapshot".utf8).write(to: destinationURL)
+    }
+
+    func restoreBackup(from backupURL: URL) async throws {}
+}
+
+private final class SteppingClock: @unchecked Sendable {
+    private let lock = NSLock()
+    private var current: Date
+    private let step: TimeInterval
+
+    init(start: Date, step: TimeInterval) {
+        self.current = start
+        self.step = step
+    }
+
+    func next() -> Date {
+        lock.withLock {
+            defer { current += step }
+            return current
+        }
+    }
+}
@@ -0,0 +1,35 @@
+import AppCore
+import Foundation
+import Testing
+
+@Test("DeepLink should parse open and search links")
+func deepLinkShouldParseSupportedActions() throws {
+    let documentID = UUID()
+    let openURL = try #require(URL(string: "filesmind://open?doc=\(documentID.uuidString)"))
+    let searchURL = try #require(URL(string: "FilesMind://search?q=%E7%9F%A5%E8%AF%86%20graph"))
+
+    #expect(DeepLink(url: openURL) == .openDocument(documentID))
+    #expect(DeepLink(url: searchURL) == .search("知识 graph"))
+}
+
+@Test("DeepLink should reject malformed links")
+func deepLinkShouldRejectMalformedLinks() throws {
+    let candidates = [
+        "https://open?doc=\(UUID().uuidString)",
+        "filesmind://open?doc=not-a-uuid",
+        "filesmind://search?q=%20%20",
+        "filesmind://delete?doc=\(UUID().uuidString)"
+    ]
+
+    for candidate in candidates {
+        let url = try #require(URL(string: candidate))
+        #expect(DeepLink(url: url) == nil)
+    }
+}
+
+@Test("DeepLink should round-trip through its URL")
+func deepLinkShouldRoundTrip() throws {
+    let link = DeepLink.search("a&b = c")
+    let url = try #require(link.url)
+    #expect(DeepLink(url: url) == link)
+}
@@ -0,0 +1,45 @@
+import AppCore
+import Domain
+import Foundation
+import SecurityKit
+import Testing
+
+@Test("EraseDocumentUseCase should erase the source, forget its password and remove the document")
+func eraseDocumentUseCaseRemovesEverything() async throws {
+    let fileURL = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-erase-\(UUID().uuidString).pdf", isDirectory: false)
+    try Data("confidential".utf8).write(to: fileURL)
+
+    let passwords = DocumentPasswordVault(usesKeychain: false)
+    try await passwords.setPassword("hunter2", for: fileURL, persist: false)
+    let remover = RecordingDocumentRemover()
+    let documentID = UUID()
+
+    try await EraseDocumentUseCase(documentRemover: remover, passwords: passwords)
+        .execute(documentID: documentID, sourceURL: fileURL)
+
+    #expect(!FileManager.default.fileExists(atPath: fileURL.path))
+    #expect(try await passwords.password(for: fileURL) == nil)
+    #expect(await remover.removedIDs == [documentID])
+}
+
+@Test("EraseDocumentUseCase should keep the document listed when the file cannot be erased")
+func eraseDocumentUseCaseKeepsDocumentOnFailure() async {
+    let missingURL = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-missing-\(UUID().uuidString).pdf", isDirectory: false)
+    let remover = RecordingDocumentRemover()
+
+    await #expect(throws: (any Error).self) {
+        try await EraseDocumentUseCase(documentRemover: remover, passwords: DocumentPasswordVault(usesKeychain: false))
+            .execute(documentID: UUID(), sourceURL: missingURL)
+    }
+    #expect(await remover.removedIDs.isEmpty)
+}
+
+private actor RecordingDocumentRemover: DocumentRemoving {
+    private(set) var removedIDs: [UUID] = []
+
+    func removeDocument(id: UUID) async throws {
+        removedIDs.append(id)
+    }
+}
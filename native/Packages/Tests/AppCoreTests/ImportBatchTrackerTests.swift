@@ -0,0 +1,27 @@
+import AppCore
+import Domain
+import Foundation
+import Testing
+
+@Test("ImportBatchTracker should report progress and summarize when the queue drains")
+func importBatchTrackerSummarizesBatch() {
+    var tracker = ImportBatchTracker()
+    let earlier = ImportJob(fileURL: URL(fileURLWithPath: "/tmp/old.md"), status: .indexed, progress: 1)
+    var first = ImportJob(fileURL: URL(fileURLWithPath: "/tmp/a.md"), status: .queued, progress: 0)
+    var second = ImportJob(fileURL: URL(fileURLWithPath: "/tmp/b.pdf"), status: .queued, progress: 0)
+
+    #expect(tracker.update(jobs: [earlier, first, second]) == nil)
+    #expect(tracker.fractionCompleted == 0)
+
+    first.status = .indexed
+    second.status = .parsing
+    #expect(tracker.update(jobs: [earlier, first, second]) == nil)
+    #expect(tracker.fractionCompleted == 0.5)
+
+    second.status = .failed
+    let summary = tracker.update(jobs: [earlier, first, second])
+    #expect(summary == ImportBatchSummary(indexedCount: 1, failedCount: 1, lockedCount: 0))
+    #expect(tracker.fractionCompleted == nil)
+
+    #expect(tracker.update(jobs: [earlier, first, second]) == nil)
+}
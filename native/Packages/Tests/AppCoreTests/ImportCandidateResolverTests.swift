@@ -0,0 +1,49 @@
+import AppCore
+import Foundation
+import Testing
+
+@Test("ImportCandidateResolver should accept supported files and explain rejections")
+func importCandidateResolverFiltersFiles() throws {
+    let root = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-candidates-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: root, withIntermediateDirectories: true)
+
+    let markdown = root.appendingPathComponent("notes.md", isDirectory: false)
+    let large = root.appendingPathComponent("large.txt", isDirectory: false)
+    let image = root.appendingPathComponent("photo.png", isDirectory: false)
+    let missing = root.appendingPathComponent("missing.pdf", isDirectory: false)
+    try "# Notes".write(to: markdown, atomically: true, encoding: .utf8)
+    try String(repeating: "x", count: 64).write(to: large, atomically: true, encoding: .utf8)
+    try "png".write(to: image, atomically: true, encoding: .utf8)
+
+    let resolver = ImportCandidateResolver(maxFileBytes: 32)
+    let candidates = resolver.resolve([markdown, markdown, large, image, missing])
+
+    #expect(candidates.accepted == [markdown.standardizedFileURL])
+    #expect(candidates.rejected.map(\.reason) == [
+        .tooLarge(bytes: 64),
+        .unsupportedType("png"),
+        .unreadable
+    ])
+}
+
+@Test("ImportCandidateResolver should expand dropped folders and honor .fmignore")
+func importCandidateResolverExpandsDirectories() throws {
+    let root = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-drop-\(UUID().uuidString)", isDirectory: true)
+    let nested = root.appendingPathComponent("chapter", isDirectory: true)
+    let ignored = root.appendingPathComponent("build", isDirectory: true)
+    try FileManager.default.createDirectory(at: nested, withIntermediateDirectories: true)
+    try FileManager.default.createDirectory(at: ignored, withIntermediateDirectories: true)
+
+    try "build/\n".write(to: root.appendingPathComponent(".fmignore"), atomically: true, encoding: .utf8)
+    try "# One".write(to: root.appendingPathComponent("one.md"), atomically: true, encoding: .utf8)
+    try "# Two".write(to: nested.appendingPathComponent("two.md"), atomically: true, encoding: .utf8)
+    try "skip".write(to: nested.appendingPathComponent("cover.png"), atomically: true, encoding: .utf8)
+    try "# Generated".write(to: ignored.appendingPathComponent("out.md"), atomically: true, encoding: .utf8)
+
+    let candidates = ImportCandidateResolver().resolveExpandingDirectories([root])
+
+    #expect(candidates.accepted.map(\.lastPathComponent) == ["one.md", "two.md"])
+    #expect(candidates.rejected.isEmpty)
+}
@@ -54,3 +54,272 @@ private actor FailingDocumentImporter: DocumentImporting {
         throw FilesMindError.validationFailed("mock import error")
     }
 }
+
+@Test("ImportQueue should run user-initiated jobs ahead of queued bulk jobs")
+func importQueueShouldPrioritizeUserInitiatedJobs() async {
+    let importer = RecordingDocumentImporter(delay: .milliseconds(60))
+    let queue = ImportQueue(
+        importer: importer,
+        configuration: ImportQueueConfiguration(maxConcurrentJobs: 1)
+    )
+
+    let bulkURLs = (0..<3).map { URL(fileURLWithPath: "/tmp/bulk-\($0).md") }
+    let userURL = URL(fileURLWithPath: "/tmp/user.md")
+
+    await queue.enqueue(fileURLs: bulkURLs, priority: .bulk)
+    await queue.enqueue(fileURLs: [userURL], priority: .userInitiated)
+
+    try? await Task.sleep(for: .milliseconds(700))
+
+    let order = await importer.importedFileNames()
+    #expect(order == ["bulk-0.md", "user.md", "bulk-1.md", "bulk-2.md"])
+
+    let status = await queue.status()
+    #expect(status.indexedCount == 4)
+    #expect(status.queuedCount == 0)
+}
+
+@Test("ImportQueue should honor a concurrency cap until it is lifted")
+func importQueueShouldHonorConcurrencyCap() async {
+    let importer = RecordingDocumentImporter(delay: .milliseconds(300))
+    let queue = ImportQueue(importer: importer)
+
+    await queue.setConcurrencyCap(1)
+    await queue.enqueue(fileURLs: (0..<3).map { URL(fileURLWithPath: "/tmp/capped-\($0).md") })
+
+    try? await Task.sleep(for: .milliseconds(50))
+    #expect(await queue.status().runningCount == 1)
+
+    await queue.setConcurrencyCap(nil)
+    try? await Task.sleep(for: .milliseconds(50))
+    #expect(await queue.status().runningCount == 2)
+}
+
+@Test("ImportQueue should promote starved jobs")
+func importQueueShouldPromoteStarvedJobs() async {
+    let importer = RecordingDocumentImporter(delay: .milliseconds(60))
+    let queue = ImportQueue(
+        importer: importer,
+        configuration: ImportQueueConfiguration(maxConcurrentJobs: 1, starvationThreshold: 0)
+    )
+
+    await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/first.md")], priority: .bulk)
+    await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/second.md")], priority: .bulk)
+    await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/third.md")], priority: .userInitiated)
+
+    try? await Task.sleep(for: .milliseconds(500))
+
+    let order = await importer.importedFileNames()
+    #expect(order == ["first.md", "second.md", "third.md"])
+}
+
+@Test("ImportQueue should reorder and clear queued jobs")
+func importQueueShouldReorderAndClearQueuedJobs() async {
+    let importer = RecordingDocumentImporter(delay: .milliseconds(80))
+    let queue = ImportQueue(
+        importer: importer,
+        configuration: ImportQueueConfiguration(maxConcurrentJobs: 1)
+    )
+
+    let urls = ["a.md", "b.md", "c.md", "d.md"].map { URL(fileURLWithPath: "/tmp/\($0)") }
+    await queue.enqueue(fileURLs: urls, priority: .bulk)
+
+    let ids = await queue.currentJobs().map(\.id)
+    let moved = await queue.reorder(jobIDs: [ids[3]])
+    #expect(moved == 1)
+
+    let didReprioritize = await queue.setPriority(.userInitiated, for: ids[2])
+    #expect(didReprioritize == true)
+
+    let statusBeforeClear = await queue.status()
+    #expect(statusBeforeClear.runningCount == 1)
+    #expect(statusBeforeClear.queued[.bulk] == 2)
+    #expect(statusBeforeClear.queued[.userInitiated] == 1)
+
+    let cleared = await queue.clear(priority: .bulk)
+    #expect(cleared == 2)
+
+    try? await Task.sleep(for: .milliseconds(400))
+
+    let order = await importer.importedFileNames()
+    let remainingJobs = await queue.currentJobs()
+    #expect(order == ["a.md", "c.md"])
+    #expect(remainingJobs.count == 2)
+}
+
+@Test("ImportQueue should release the slot of a job cleared before it started")
+func importQueueShouldReleaseSlotOfClearedJob() async {
+    let importer = RecordingDocumentImporter(delay: .milliseconds(10))
+    let queue = ImportQueue(
+        importer: importer,
+        configuration: ImportQueueConfiguration(maxConcurrentJobs: 1)
+    )
+
+    // Clearing right after enqueue can land between scheduling and the job's first step.
+    for index in 0..<20 {
+        await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/cleared-\(index).md")])
+        await queue.clear()
+    }
+    try? await Task.sleep(for: .milliseconds(300))
+
+    await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/later.md")])
+    try? await Task.sleep(for: .milliseconds(300))
+
+    let later = await queue.currentJobs().first { $0.fileURL.lastPathComponent == "later.md" }
+    #expect(later?.status == .indexed)
+    #expect(await queue.status().runningCount == 0)
+}
+
+@Test("ImportQueue should drain running jobs on pause and hold queued ones until resumed")
+func importQueueShouldPauseAndResume() async {
+    let importer = RecordingDocumentImporter(delay: .milliseconds(150))
+    let queue = ImportQueue(
+        importer: importer,
+        configuration: ImportQueueConfiguration(maxConcurrentJobs: 1)
+    )
+
+    await queue.enqueue(fileURLs: ["first.md", "second.md"].map { URL(fileURLWithPath: "/tmp/\($0)") })
+    try? await Task.sleep(for: .milliseconds(20))
+    await queue.pause()
+
+    let paused = await queue.status()
+    #expect(paused.runningCount == 0)
+    #expect(paused.indexedCount == 1)
+    #expect(paused.queuedCount == 1)
+
+    try? await Task.sleep(for: .milliseconds(200))
+    #expect(await importer.importedFileNames() == ["first.md"])
+
+    await queue.resume()
+    try? await Task.sleep(for: .milliseconds(300))
+    #expect(await importer.importedFileNames() == ["first.md", "second.md"])
+    #expect(await queue.status().indexedCount == 2)
+}
+
+private actor RecordingDocumentImporter: DocumentImporting {
+    private let delay: Duration
+    private var fileNames: [String] = []
+
+    init(delay: Duration) {
+        self.delay = delay
+    }
+
+    func importedFileNames() -> [String] {
+        fileNames
+    }
+
+    func importDocument(at fileURL: URL) async throws -> ParsedDocument {
+        fileNames.append(fileURL.lastPathComponent)
+        try? await Task.sleep(for: delay)
+        let docID = UUID()
+        return ParsedDocument(
+            documentID: docID,
+            sourceURL: fileURL,
+            title: fileURL.lastPathComponent,
+            sourceType: .markdown,
+            chunks: [Chunk(documentID: docID, ordinal: 0, text: fileURL.lastPathComponent)]
+        )
+    }
+}
+
+@Test("ImportQueue should retry transient failures before succeeding")
+func importQueueShouldRetryTransientFailures() async throws {
+    let importer = FlakyDocumentImporter(failuresBeforeSuccess: 2)
+    let store = MockFailedImportStore()
+    let queue = ImportQueue(
+        importer: importer,
+        failedImportStore: store,
+        configuration: ImportQueueConfiguration(
+            retryPolicy: ImportRetryPolicy(maxAttempts: 3, initialDelay: 0.05, backoffMultiplier: 1)
+        )
+    )
+
+    await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/flaky.md")])
+
+    try? await Task.sleep(for: .milliseconds(500))
+
+    let jobs = await queue.currentJobs()
+    #expect(jobs.first?.status == .indexed)
+    #expect(jobs.first?.attemptCount == 3)
+
+    let failed = try await queue.failedImports()
+    #expect(failed.isEmpty)
+}
+
+@Test("ImportQueue should dead-letter permanent failures and allow retry or dismiss")
+func importQueueShouldDeadLetterPermanentFailures() async throws {
+    let store = MockFailedImportStore()
+    let queue = ImportQueue(
+        importer: FailingDocumentImporter(),
+        failedImportStore: store,
+        configuration: ImportQueueConfiguration(
+            retryPolicy: ImportRetryPolicy(maxAttempts: 3, initialDelay: 0.05, backoffMultiplier: 1)
+        )
+    )
+
+    await queue.enqueue(fileURLs: [URL(fileURLWithPath: "/tmp/broken.md")], priority: .bulk)
+
+    try? await Task.sleep(for: .milliseconds(200))
+
+    let failed = try await queue.failedImports()
+    #expect(failed.count == 1)
+    #expect(failed.first?.attemptCount == 1)
+    #expect(failed.first?.priority == .bulk)
+
+    guard let record = failed.first else { return }
+    let didRetry = try await queue.retryFailedImport(id: record.id)
+    #expect(didRetry == true)
+
+    try? await Task.sleep(for: .milliseconds(200))
+
+    let failedAfterRetry = try await queue.failedImports()
+    #expect(failedAfterRetry.count == 1)
+    #expect(failedAfterRetry.first?.id != record.id)
+
+    guard let retried = failedAfterRetry.first else { return }
+    try await queue.dismissFailedImport(id: retried.id)
+
+    let failedAfterDismiss = try await queue.failedImports()
+    let jobs = await queue.currentJobs()
+    #expect(failedAfterDismiss.isEmpty)
+    #expect(jobs.isEmpty)
+}
+
+private actor FlakyDocumentImporter: DocumentImporting {
+    private var remainingFailures: Int
+
+    init(failuresBeforeSuccess: Int) {
+        self.remainingFailures = failuresBeforeSuccess
+    }
+
+    func importDocument(at fileURL: URL) async throws -> ParsedDocument {
+        if remainingFailures > 0 {
+            remainingFailures -= 1
+            throw FilesMindError.invalidState("mock transient error")
+        }
+        let docID = UUID()
+        return ParsedDocument(
+            documentID: docID,
+            sourceURL: fileURL,
+            title: fileURL.lastPathComponent,
+            sourceType: .markdown,
+            chunks: [Chunk(documentID: docID, ordinal: 0, text: "recovered")]
+        )
+    }
+}
+
+private actor MockFailedImportStore: FailedImportStore {
+    private var records: [UUID: FailedImportRecord] = [:]
+
+    func upsertFailedImport(_ record: FailedImportRecord) async throws {
+        records[record.id] = record
+    }
+
+    func failedImports() async throws -> [FailedImportRecord] {
+        records.values.sorted(by: { $0.failedAt > $1.failedAt })
+    }
+
+    func removeFailedImport(id: UUID) async throws {
+        records[id] = nil
+    }
+}
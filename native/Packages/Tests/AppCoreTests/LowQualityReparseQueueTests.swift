@@ -68,6 +68,68 @@ func lowQualityReparseQueueShouldRejectDuplicateActiveJobs() async throws {
     #expect(jobs.count == 1)
 }
 
+@Test("LowQualityReparseQueue should finish running jobs on pause and hold new ones until resumed")
+func lowQualityReparseQueueShouldPauseAndResume() async throws {
+    let running = ImportedDocumentRecord(
+        id: UUID(),
+        sourcePath: "/tmp/running.pdf",
+        title: "running",
+        sourceType: .pdf,
+        chunkCount: 4,
+        lowQualityPages: [0],
+        importedAt: Date()
+    )
+    let held = ImportedDocumentRecord(
+        id: UUID(),
+        sourcePath: "/tmp/held.pdf",
+        title: "held",
+        sourceType: .pdf,
+        chunkCount: 4,
+        lowQualityPages: [1],
+        importedAt: Date()
+    )
+    let queue = LowQualityReparseQueue(
+        reparser: MockLowQualityReparser(resolvedPages: [0, 1]),
+        documentStore: MockDocumentStore(document: running, sections: [])
+    )
+
+    _ = await queue.enqueue(document: running)
+    await queue.pause()
+    #expect(await queue.currentJobs().first?.status == .completed)
+
+    _ = await queue.enqueue(document: held)
+    try? await Task.sleep(for: .milliseconds(200))
+    #expect(await queue.currentJobs().last?.status == .queued)
+
+    await queue.resume()
+    try? await Task.sleep(for: .milliseconds(300))
+    #expect(await queue.currentJobs().last?.status == .completed)
+}
+
+@Test("LowQualityReparseQueue should drop jobs held back by a pause when cleared")
+func lowQualityReparseQueueShouldClearHeldJobs() async throws {
+    let held = ImportedDocumentRecord(
+        id: UUID(),
+        sourcePath: "/tmp/held.pdf",
+        title: "held",
+        sourceType: .pdf,
+        chunkCount: 4,
+        lowQualityPages: [1],
+        importedAt: Date()
+    )
+    let store = MockDocumentStore(document: held, sections: [])
+    let queue = LowQualityReparseQueue(reparser: MockLowQualityReparser(resolvedPages: [1]), documentStore: store)
+
+    await queue.pause()
+    _ = await queue.enqueue(document: held)
+    #expect(await queue.clear() == 1)
+    await queue.resume()
+
+    try? await Task.sleep(for: .milliseconds(200))
+    #expect(await queue.currentJobs().isEmpty)
+    #expect(try await store.recentDocuments(limit: 1).first?.lowQualityPages == [1])
+}
+
 private actor MockLowQualityReparser: LowQualityPageReparsing {
     private let resolvedPages: [Int]
 
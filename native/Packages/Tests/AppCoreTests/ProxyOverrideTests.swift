@@ -0,0 +1,44 @@
+import AppCore
+import Foundation
+import Testing
+
+@Test("ProxyOverride should parse host:port and proxy URLs")
+func proxyOverrideParsesInput() {
+    #expect(ProxyOverride(string: "proxy.corp.example:3128") == ProxyOverride(host: "proxy.corp.example", port: 3128))
+    #expect(ProxyOverride(string: " https://10.0.0.2:8443 ") == ProxyOverride(host: "10.0.0.2", port: 8443))
+    #expect(ProxyOverride(string: "proxy.corp.example") == ProxyOverride(host: "proxy.corp.example", port: 8080))
+    #expect(ProxyOverride(string: "socks5://proxy:1080") == nil)
+    #expect(ProxyOverride(string: "  ") == nil)
+}
+
+@Test("ProxyOverride should route HTTP and HTTPS through the proxy")
+func proxyOverrideConfiguresSession() {
+    let configuration = ProxyOverride(host: "proxy.corp.example", port: 3128)
+        .makeSessionConfiguration(base: .ephemeral)
+    let proxies = configuration.connectionProxyDictionary ?? [:]
+
+    #expect(proxies[kCFNetworkProxiesHTTPSProxy as String] as? String == "proxy.corp.example")
+    #expect(proxies[kCFNetworkProxiesHTTPSPort as String] as? Int == 3128)
+    #expect(proxies[kCFNetworkProxiesHTTPProxy as String] as? String == "proxy.corp.example")
+}
+
+@Test("SystemProxySetting should read manual, automatic and direct settings")
+func systemProxySettingReadsSystemSettings() {
+    let manual: [String: Any] = [
+        kCFNetworkProxiesHTTPEnable as String: 1,
+        kCFNetworkProxiesHTTPProxy as String: "http-only.example",
+        kCFNetworkProxiesHTTPPort as String: 80,
+        kCFNetworkProxiesHTTPSEnable as String: 1,
+        kCFNetworkProxiesHTTPSProxy as String: "proxy.corp.example",
+        kCFNetworkProxiesHTTPSPort as String: 3128
+    ]
+    #expect(SystemProxySetting(settings: manual) == .manual(ProxyOverride(host: "proxy.corp.example", port: 3128)))
+
+    let pac: [String: Any] = [
+        kCFNetworkProxiesProxyAutoConfigEnable as String: 1,
+        kCFNetworkProxiesProxyAutoConfigURLString as String: "http://wpad.corp.example/proxy.pac"
+    ]
+    #expect(SystemProxySetting(settings: pac) == .automatic(URL(string: "http://wpad.corp.example/proxy.pac")))
+
+    #expect(SystemProxySetting(settings: [kCFNetworkProxiesHTTPSEnable as String: 0]) == .direct)
+}
@@ -0,0 +1,98 @@
+import AppCore
+import Domain
+import Foundation
+import SecurityKit
+import Testing
+
+@Test("ResetLibraryUseCase should erase the store, the passwords and the app's folders")
+func resetLibraryUseCaseRemovesEverything() async throws {
+    let folder = try makeResetFolder()
+    let passwords = DocumentPasswordVault(usesKeychain: false)
+    let documentURL = URL(fileURLWithPath: "/tmp/locked.pdf")
+    try await passwords.setPassword("hunter2", for: documentURL, persist: false)
+    let store = RecordingLibraryEraser(fails: false)
+
+    let modelsFolder = try makeResetFolder()
+
+    let report = await ResetLibraryUseCase(
+        store: store,
+        backupScheduler: nil,
+        passwords: passwords,
+        folders: [folder],
+        modelsFolder: modelsFolder,
+        settingsDomain: nil
+    ).execute()
+
+    #expect(report.failures.isEmpty)
+    #expect(report.leftBehind == [.models(modelsFolder), .settings])
+    #expect(await store.eraseCount == 1)
+    #expect(try await passwords.password(for: documentURL) == nil)
+    #expect(!FileManager.default.fileExists(atPath: folder.path))
+    #expect(FileManager.default.fileExists(atPath: modelsFolder.path))
+}
+
+@Test("ResetLibraryUseCase should remove models and settings when asked to")
+func resetLibraryUseCaseRemovesOptInItems() async throws {
+    let modelsFolder = try makeResetFolder()
+    let suiteName = "filesmind-reset-\(UUID().uuidString)"
+    let settings = try #require(UserDefaults(suiteName: suiteName))
+    settings.set(true, forKey: "indexesInSpotlight")
+
+    let report = await ResetLibraryUseCase(
+        store: RecordingLibraryEraser(fails: false),
+        backupScheduler: nil,
+        passwords: DocumentPasswordVault(usesKeychain: false),
+        folders: [],
+        modelsFolder: modelsFolder,
+        settings: settings,
+        settingsDomain: suiteName
+    ).execute(removingModels: true, removingSettings: true)
+
+    #expect(report.leftBehind.isEmpty)
+    #expect(!FileManager.default.fileExists(atPath: modelsFolder.path))
+    #expect(settings.object(forKey: "indexesInSpotlight") == nil)
+}
+
+@Test("ResetLibraryUseCase should keep going and report what it could not remove")
+func resetLibraryUseCaseReportsFailures() async throws {
+    let folder = try makeResetFolder()
+
+    let modelsFolder = try makeResetFolder()
+
+    let report = await ResetLibraryUseCase(
+        store: RecordingLibraryEraser(fails: true),
+        backupScheduler: nil,
+        passwords: DocumentPasswordVault(usesKeychain: false),
+        folders: [folder],
+        modelsFolder: modelsFolder,
+        settingsDomain: nil
+    ).execute(removingSettings: true)
+
+    #expect(report.failures.map(\.item) == [.library, .settings])
+    #expect(report.leftBehind == [.library, .settings, .models(modelsFolder)])
+    #expect(!FileManager.default.fileExists(atPath: folder.path))
+}
+
+private func makeResetFolder() throws -> URL {
+    let folder = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-reset-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: folder, withIntermediateDirectories: true)
+    try Data("capture".utf8).write(to: folder.appendingPathComponent("note.md"))
+    return folder
+}
+
+private actor RecordingLibraryEraser: LibraryErasing {
+    private let fails: Bool
+    private(set) var eraseCount = 0
+
+    init(fails: Bool) {
+        self.fails = fails
+    }
+
+    func eraseLibrary() async throws {
+        eraseCount += 1
+        if fails {
+            throw FilesMindError.invalidState("Database is locked")
+        }
+    }
+}
@@ -0,0 +1,62 @@
+import AppCore
+import Domain
+import Foundation
+import Testing
+
+@Test("RestoreWorkspaceUseCase should reopen the saved workspace")
+func restoreWorkspaceStartsScopedAccess() async throws {
+    let manager = MockWorkspaceAuthorizationManager()
+    let workspaceID = WorkspaceID("workspace-a")
+    let directoryURL = URL(fileURLWithPath: "/tmp/workspace-a", isDirectory: true)
+    _ = try await manager.authorizeWorkspace(id: workspaceID, directoryURL: directoryURL)
+
+    let handle = try await RestoreWorkspaceUseCase(bookmarkManager: manager).execute(workspaceID: workspaceID)
+
+    #expect(handle?.directoryURL == directoryURL)
+    #expect(await manager.startedIDs == [workspaceID])
+}
+
+@Test("RestoreWorkspaceUseCase should return nil when no workspace was saved")
+func restoreWorkspaceWithoutBookmark() async throws {
+    let manager = MockWorkspaceAuthorizationManager()
+
+    let handle = try await RestoreWorkspaceUseCase(bookmarkManager: manager).execute(workspaceID: WorkspaceID("missing"))
+
+    #expect(handle == nil)
+    #expect(await manager.startedIDs.isEmpty)
+}
+
+private actor MockWorkspaceAuthorizationManager: WorkspaceAuthorizationManaging {
+    private var authorizations: [WorkspaceID: WorkspaceAuthorization] = [:]
+    private(set) var startedIDs: [WorkspaceID] = []
+
+    func authorizeWorkspace(id: WorkspaceID, directoryURL: URL) async throws -> WorkspaceAuthorization {
+        let authorization = WorkspaceAuthorization(
+            workspaceID: id,
+            directoryURL: directoryURL,
+            bookmarkData: Data(directoryURL.path.utf8),
+            isStale: false
+        )
+        authorizations[id] = authorization
+        return authorization
+    }
+
+    func resolveAuthorization(id: WorkspaceID) async throws -> WorkspaceAuthorization {
+        guard let authorization = authorizations[id] else {
+            throw FilesMindError.notFound("Bookmark missing for workspace \(id.rawValue)")
+        }
+        return authorization
+    }
+
+    func startScopedAccess(id: WorkspaceID) async throws -> WorkspaceAccessHandle {
+        let authorization = try await resolveAuthorization(id: id)
+        startedIDs.append(id)
+        return WorkspaceAccessHandle(workspaceID: id, directoryURL: authorization.directoryURL, beganScopedAccess: true)
+    }
+
+    func stopScopedAccess(_ handle: WorkspaceAccessHandle) async {}
+
+    func revokeAuthorization(id: WorkspaceID) async throws {
+        authorizations[id] = nil
+    }
+}
@@ -0,0 +1,67 @@
+import AppCore
+import Foundation
+import Testing
+
+@Test("WorkspaceFileWalker should not loop on symlink cycles or double count hardlinks")
+func workspaceFileWalkerGuardsCyclesAndHardlinks() throws {
+    let fileManager = FileManager.default
+    let root = try makeWalkerFixture()
+    try fileManager.createSymbolicLink(
+        at: root.appendingPathComponent("notes/loop", isDirectory: true),
+        withDestinationURL: root
+    )
+    try fileManager.linkItem(
+        at: root.appendingPathComponent("notes/a.md"),
+        to: root.appendingPathComponent("notes/a-hardlink.md")
+    )
+
+    var visited: [String] = []
+    let stats = try WorkspaceFileWalker(symlinkPolicy: .followWithinRoot).walk(root) { url, _ in
+        visited.append(url.lastPathComponent)
+        return true
+    }
+
+    #expect(visited == ["a-hardlink.md", "b.md"])
+    #expect(stats.cyclesSkipped == 1)
+    #expect(stats.duplicateHardlinks == 1)
+}
+
+@Test("WorkspaceFileWalker should apply the symlink policy")
+func workspaceFileWalkerAppliesSymlinkPolicy() throws {
+    let fileManager = FileManager.default
+    let root = try makeWalkerFixture()
+    let outside = try makeWalkerFixture()
+    try fileManager.createSymbolicLink(
+        at: root.appendingPathComponent("external", isDirectory: true),
+        withDestinationURL: outside.appendingPathComponent("notes", isDirectory: true)
+    )
+
+    func visitedCount(_ policy: SymlinkPolicy) throws -> (Int, WorkspaceWalkStats) {
+        var count = 0
+        let stats = try WorkspaceFileWalker(symlinkPolicy: policy).walk(root) { _, _ in
+            count += 1
+            return true
+        }
+        return (count, stats)
+    }
+
+    let (skipped, skipStats) = try visitedCount(.skip)
+    #expect(skipped == 2)
+    #expect(skipStats.skippedSymlinks == 1)
+
+    let (withinRoot, _) = try visitedCount(.followWithinRoot)
+    #expect(withinRoot == 2)
+
+    let (anywhere, _) = try visitedCount(.followAnywhere)
+    #expect(anywhere == 4)
+}
+
+private func makeWalkerFixture() throws -> URL {
+    let root = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-walk-\(UUID().uuidString)", isDirectory: true)
+    let notes = root.appendingPathComponent("notes", isDirectory: true)
+    try FileManager.default.createDirectory(at: notes, withIntermediateDirectories: true)
+    try "alpha".write(to: notes.appendingPathComponent("a.md"), atomically: true, encoding: .utf8)
+    try "beta".write(to: notes.appendingPathComponent("b.md"), atomically: true, encoding: .utf8)
+    return root
+}
@@ -0,0 +1,90 @@
+import AppCore
+import Domain
+import Foundation
+import Testing
+
+@Test("WorkspaceFolderEstimator should count supported files by type")
+func workspaceFolderEstimatorCountsSupportedFiles() async throws {
+    let root = try makeEstimatorFixture(files: [
+        "notes/a.md": String(repeating: "a", count: 100),
+        "notes/b.markdown": String(repeating: "b", count: 50),
+        "papers/c.pdf": String(repeating: "c", count: 400),
+        "misc/d.png": String(repeating: "d", count: 10)
+    ])
+
+    let estimate = try await WorkspaceFolderEstimator(
+        projection: WorkspaceIndexProjection(
+            indexBytesPerTextByte: 2,
+            indexBytesPerPDFByte: 0.5,
+            textBytesPerSecond: 150,
+            pdfBytesPerSecond: 400
+        )
+    ).estimate(directoryURL: root)
+
+    #expect(estimate.fileCount == 4)
+    #expect(estimate.supportedFileCount == 3)
+    #expect(estimate.totalBytes == 560)
+    #expect(estimate.supportedBytes == 550)
+    #expect(estimate.fileCountsByExtension["png"] == 1)
+    #expect(estimate.projectedIndexBytes == 500)
+    #expect(estimate.projectedIndexDuration == 2)
+    #expect(estimate.isPartial == false)
+}
+
+@Test("WorkspaceFolderEstimator should stop at the sampling limit")
+func workspaceFolderEstimatorStopsAtLimit() async throws {
+    var files: [String: String] = [:]
+    for index in 0..<20 {
+        files["file-\(index).md"] = "content"
+    }
+    let root = try makeEstimatorFixture(files: files)
+
+    let estimate = try await WorkspaceFolderEstimator(maxVisitedEntries: 5).estimate(directoryURL: root)
+
+    #expect(estimate.fileCount == 5)
+    #expect(estimate.isPartial == true)
+    #expect(estimate.warnings.contains(.sampleLimitReached(visitedEntries: 5)))
+}
+
+@Test("WorkspaceFolderEstimator should skip entries excluded by .fmignore")
+func workspaceFolderEstimatorHonorsIgnoreFile() async throws {
+    let root = try makeEstimatorFixture(files: [
+        ".fmignore": "node_modules/\n*.pdf\n",
+        "notes/a.md": "keep",
+        "node_modules/pkg/readme.md": "skip",
+        "papers/c.pdf": "skip"
+    ])
+
+    let estimate = try await WorkspaceFolderEstimator().estimate(directoryURL: root)
+
+    #expect(estimate.fileCount == 1)
+    #expect(estimate.supportedFileCount == 1)
+    #expect(estimate.ignoredEntryCount == 2)
+}
+
+@Test("WorkspaceFolderEstimator should report allocated size for sparse files")
+func workspaceFolderEstimatorReportsAllocatedBytes() async throws {
+    let root = try makeEstimatorFixture(files: ["notes/a.md": "alpha"])
+    let sparseURL = root.appendingPathComponent("sparse.pdf", isDirectory: false)
+    FileManager.default.createFile(atPath: sparseURL.path, contents: nil)
+    let handle = try FileHandle(forWritingTo: sparseURL)
+    try handle.seek(toOffset: 64 * 1024 * 1024)
+    try handle.write(contentsOf: Data([0x1]))
+    try handle.close()
+
+    let estimate = try await WorkspaceFolderEstimator().estimate(directoryURL: root)
+
+    #expect(estimate.totalBytes == 64 * 1024 * 1024 + 1 + 5)
+    #expect(estimate.allocatedBytes < estimate.totalBytes)
+}
+
+private func makeEstimatorFixture(files: [String: String]) throws -> URL {
+    let root = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-estimate-\(UUID().uuidString)", isDirectory: true)
+    for (relativePath, contents) in files {
+        let url = root.appendingPathComponent(relativePath, isDirectory: false)
+        try FileManager.default.createDirectory(at: url.deletingLastPathComponent(), withIntermediateDirectories: true)
+        try contents.write(to: url, atomically: true, encoding: .utf8)
+    }
+    return root
+}
@@ -0,0 +1,40 @@
+import AppCore
+import Testing
+
+@Test("WorkspaceIgnoreRules should match gitignore-style patterns")
+func workspaceIgnoreRulesMatchPatterns() {
+    let rules = WorkspaceIgnoreRules(contents: """
+    # build output
+    node_modules/
+    *.log
+    /drafts
+    docs/**/private.md
+    build/
+    !keep.log
+    """)
+
+    #expect(rules.isIgnored(relativePath: "node_modules", isDirectory: true))
+    #expect(rules.isIgnored(relativePath: "web/node_modules/pkg/readme.md", isDirectory: false))
+    #expect(rules.isIgnored(relativePath: "logs/debug.log", isDirectory: false))
+    #expect(!rules.isIgnored(relativePath: "logs/keep.log", isDirectory: false))
+    #expect(rules.isIgnored(relativePath: "drafts/idea.md", isDirectory: false))
+    #expect(!rules.isIgnored(relativePath: "notes/drafts/idea.md", isDirectory: false))
+    #expect(rules.isIgnored(relativePath: "docs/private.md", isDirectory: false))
+    #expect(rules.isIgnored(relativePath: "docs/a/b/private.md", isDirectory: false))
+    #expect(!rules.isIgnored(relativePath: "build", isDirectory: false))
+    #expect(!rules.isIgnored(relativePath: "notes/readme.md", isDirectory: false))
+}
+
+@Test("WorkspaceIgnoreRules should not re-include files under an ignored directory")
+func workspaceIgnoreRulesKeepParentExclusion() {
+    let rules = WorkspaceIgnoreRules(contents: """
+    vendor/
+    !vendor/notes.md
+    [Tt]mp*
+    """)
+
+    #expect(rules.isIgnored(relativePath: "vendor/notes.md", isDirectory: false))
+    #expect(rules.isIgnored(relativePath: "Tmp-1.md", isDirectory: false))
+    #expect(rules.isIgnored(relativePath: "a/tmpfile.pdf", isDirectory: false))
+    #expect(!rules.isIgnored(relativePath: "a/xtmp.pdf", isDirectory: false))
+}
@@ -27,3 +27,51 @@ func parseMarkdownIntoChunks() async throws {
     #expect(parsed.sections.count == 1)
     #expect(parsed.sections.first?.title == "Title")
 }
+
+#if canImport(PDFKit)
+import PDFKit
+
+@Test("DefaultDocumentParser should require a password for locked PDFs")
+func parseLockedPDFRequiresPassword() async throws {
+    let tempURL = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-locked-\(UUID().uuidString).pdf", isDirectory: false)
+
+    let document = PDFDocument()
+    document.insert(PDFPage(), at: 0)
+    let didWrite = document.write(
+        to: tempURL,
+        withOptions: [.userPasswordOption: "secret", .ownerPasswordOption: "owner"]
+    )
+    #expect(didWrite)
+
+    let passwords = MockPasswordProvider()
+    let parser = DefaultDocumentParser(router: PipelineRouter(), passwordProvider: passwords)
+
+    do {
+        _ = try await parser.parse(fileURL: tempURL)
+        Issue.record("Expected locked PDF to require a password")
+    } catch FilesMindError.passwordRequired(_) {
+    }
+
+    await passwords.setPassword("secret")
+
+    // The fixture page is blank, so a successful unlock surfaces as "no extractable text".
+    do {
+        _ = try await parser.parse(fileURL: tempURL)
+        Issue.record("Expected blank PDF to fail text extraction")
+    } catch FilesMindError.validationFailed(_) {
+    }
+}
+
+private actor MockPasswordProvider: DocumentPasswordProviding {
+    private var storedPassword: String?
+
+    func setPassword(_ password: String) {
+        storedPassword = password
+    }
+
+    func password(for fileURL: URL) async throws -> String? {
+        storedPassword
+    }
+}
+#endif
@@ -0,0 +1,17 @@
+import DocumentPipeline
+import Testing
+
+#if canImport(NaturalLanguage)
+@Test("LanguageDetector should identify the dominant language")
+func detectDominantLanguage() {
+    let detector = LanguageDetector()
+
+    #expect(detector.detectLanguage(in: "The quick brown fox jumps over the lazy dog near the river bank.") == "en")
+    #expect(detector.detectLanguage(in: "这是一个用于测试语言识别的简体中文段落，内容关于知识管理。") == "zh-Hans")
+}
+#endif
+
+@Test("LanguageDetector should skip text without letters")
+func detectLanguageSkipsNonLetters() {
+    #expect(LanguageDetector().detectLanguage(in: "1234 5678 -- 42") == nil)
+}
@@ -0,0 +1,38 @@
+import Domain
+import Foundation
+import Testing
+
+@Test("ModelDescriptor should decode catalogs written before the memory requirement")
+func modelDescriptorShouldDecodeWithoutMinimumMemory() throws {
+    let json = """
+    {
+      "modelID": "lite-1",
+      "displayName": "Lite",
+      "tier": "lite",
+      "remoteURL": "https://example.com/lite-1.gguf",
+      "sha256": "abc123",
+      "bytes": 1024
+    }
+    """
+
+    let descriptor = try JSONDecoder().decode(ModelDescriptor.self, from: Data(json.utf8))
+    #expect(descriptor.modelID == "lite-1")
+    #expect(descriptor.tier == .lite)
+    #expect(descriptor.minimumMemoryBytes == 0)
+}
+
+@Test("ModelDescriptor should round-trip its memory requirement")
+func modelDescriptorShouldRoundTripMinimumMemory() throws {
+    let descriptor = ModelDescriptor(
+        modelID: "pro-1",
+        displayName: "Pro",
+        tier: .pro,
+        remoteURL: URL(string: "https://example.com/pro-1.gguf")!,
+        sha256: "def456",
+        bytes: 4096,
+        minimumMemoryBytes: 16 * 1024 * 1024 * 1024
+    )
+
+    let decoded = try JSONDecoder().decode(ModelDescriptor.self, from: JSONEncoder().encode(descriptor))
+    #expect(decoded == descriptor)
+}
@@ -0,0 +1,21 @@
+import Domain
+import Testing
+
+@Test("SearchTextNormalizer should fold full-width and half-width forms")
+func searchKeyShouldFoldWidth() {
+    #expect(SearchTextNormalizer.searchKey("ＦｉｌｅｓＭｉｎｄ　２０２６") == "filesmind 2026")
+    #expect(SearchTextNormalizer.searchKey("ｶﾀｶﾅ") == SearchTextNormalizer.searchKey("カタカナ"))
+}
+
+@Test("SearchTextNormalizer should match decomposed and composed text")
+func searchKeyShouldComposeUnicode() {
+    let decomposed = "\u{30CF}\u{309A}\u{30EA}"
+    let composed = "\u{30D1}\u{30EA}"
+    #expect(SearchTextNormalizer.searchKey(decomposed) == SearchTextNormalizer.searchKey(composed))
+    #expect(SearchTextNormalizer.displayForm("Cafe\u{301}") == "Caf\u{E9}")
+}
+
+@Test("SearchTextNormalizer should fold traditional Chinese to simplified")
+func searchKeyShouldFoldTraditionalChinese() {
+    #expect(SearchTextNormalizer.searchKey("知識圖譜") == SearchTextNormalizer.searchKey("知识图谱"))
+}
@@ -0,0 +1,261 @@
+import CryptoKit
+import Domain
+import Foundation
+import ModelManager
+import Testing
+
+@Test("DefaultModelManager should resume a partial download with a range request")
+func modelManagerResumesPartialDownload() async throws {
+    let payload = Data((0..<4_096).map { UInt8($0 % 251) })
+    RangeServingURLProtocol.serve(payload, at: "/resumable.safetensors")
+
+    let installRoot = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-models-\(UUID().uuidString)", isDirectory: true)
+    let modelDir = installRoot.appendingPathComponent("resumable-model", isDirectory: true)
+    try FileManager.default.createDirectory(at: modelDir, withIntermediateDirectories: true)
+    try payload.prefix(1_000).write(to: modelDir.appendingPathComponent("model.safetensors.partial"))
+
+    let descriptor = ModelDescriptor(
+        modelID: "resumable-model",
+        displayName: "Resumable Model",
+        tier: .standard,
+        remoteURL: URL(string: "https://models.filesmind.test/resumable.safetensors")!,
+        sha256: SHA256.hash(data: payload).map { String(format: "%02x", $0) }.joined(),
+        bytes: Int64(payload.count)
+    )
+
+    let configuration = URLSessionConfiguration.ephemeral
+    configuration.protocolClasses = [RangeServingURLProtocol.self]
+    let manager = DefaultModelManager(
+        catalog: StaticModelCatalog(models: [descriptor]),
+        validator: SHA256ArtifactValidator(),
+        installRoot: installRoot,
+        session: URLSession(configuration: configuration),
+        downloadBufferSize: 512
+    )
+
+    let installedURL = try await manager.install(modelID: descriptor.modelID, progress: nil)
+
+    #expect(RangeServingURLProtocol.requestedRanges(for: "/resumable.safetensors") == ["bytes=1000-"])
+    #expect(try Data(contentsOf: installedURL) == payload)
+    #expect(!FileManager.default.fileExists(atPath: modelDir.appendingPathComponent("model.safetensors.partial").path))
+
+    let installed = try await manager.installedModels()
+    #expect(installed.map(\.modelID) == ["resumable-model"])
+    #expect(installed.first?.bytes == Int64(payload.count))
+}
+
+@Test("DefaultModelManager should write a body that arrives in many chunks intact")
+func modelManagerWritesChunkedBody() async throws {
+    let payload = Data((0..<64_000).map { UInt8($0 % 239) })
+    RangeServingURLProtocol.serve(payload, at: "/chunked.safetensors", chunkSize: 1_500)
+
+    let installRoot = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-models-\(UUID().uuidString)", isDirectory: true)
+    let descriptor = ModelDescriptor(
+        modelID: "chunked-model",
+        displayName: "Chunked Model",
+        tier: .standard,
+        remoteURL: URL(string: "https://models.filesmind.test/chunked.safetensors")!,
+        sha256: SHA256.hash(data: payload).map { String(format: "%02x", $0) }.joined(),
+        bytes: Int64(payload.count)
+    )
+
+    let configuration = URLSessionConfiguration.ephemeral
+    configuration.protocolClasses = [RangeServingURLProtocol.self]
+    // A buffer smaller than the body makes the reader suspend and resume the task along the way.
+    let manager = DefaultModelManager(
+        catalog: StaticModelCatalog(models: [descriptor]),
+        validator: SHA256ArtifactValidator(),
+        installRoot: installRoot,
+        session: URLSession(configuration: configuration),
+        downloadBufferSize: 2_048
+    )
+
+    let reported = ProgressLog()
+    let installedURL = try await manager.install(modelID: descriptor.modelID) { reported.append($0.receivedBytes) }
+
+    #expect(try Data(contentsOf: installedURL) == payload)
+    #expect(reported.values.last == Int64(payload.count))
+    #expect(reported.values == reported.values.sorted())
+}
+
+@Test("DefaultModelManager should take at least bytes over limit seconds for a throttled download")
+func modelManagerThrottlesDownload() async throws {
+    let payload = Data((0..<16_000).map { UInt8($0 % 241) })
+    let limit = 8_000
+    RangeServingURLProtocol.serve(payload, at: "/throttled.safetensors", chunkSize: 1_000)
+
+    let installRoot = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-models-\(UUID().uuidString)", isDirectory: true)
+    let descriptor = ModelDescriptor(
+        modelID: "throttled-model",
+        displayName: "Throttled Model",
+        tier: .standard,
+        remoteURL: URL(string: "https://models.filesmind.test/throttled.safetensors")!,
+        sha256: SHA256.hash(data: payload).map { String(format: "%02x", $0) }.joined(),
+        bytes: Int64(payload.count)
+    )
+
+    let configuration = URLSessionConfiguration.ephemeral
+    configuration.protocolClasses = [RangeServingURLProtocol.self]
+    let manager = DefaultModelManager(
+        catalog: StaticModelCatalog(models: [descriptor]),
+        validator: SHA256ArtifactValidator(),
+        installRoot: installRoot,
+        session: URLSession(configuration: configuration),
+        maxDownloadBytesPerSecond: limit,
+        downloadBufferSize: 2_048
+    )
+
+    let startedAt = Date()
+    let installedURL = try await manager.install(modelID: descriptor.modelID) { _ in }
+    let elapsed = Date().timeIntervalSince(startedAt)
+
+    #expect(try Data(contentsOf: installedURL) == payload)
+    #expect(elapsed >= Double(payload.count) / Double(limit))
+}
+
+private final class ProgressLog: @unchecked Sendable {
+    private let lock = NSLock()
+    private var received: [Int64] = []
+
+    var values: [Int64] { lock.withLock { received } }
+
+    func append(_ value: Int64) {
+        lock.withLock { received.append(value) }
+    }
+}
+
+@Test("DefaultModelManager should hold a download while offline and start it once connectivity returns")
+func modelManagerWaitsForConnectivity() async throws {
+    let payload = Data((0..<2_048).map { UInt8($0 % 241) })
+    RangeServingURLProtocol.serve(payload, at: "/offline.safetensors")
+
+    let installRoot = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-models-\(UUID().uuidString)", isDirectory: true)
+    let descriptor = ModelDescriptor(
+        modelID: "offline-model",
+        displayName: "Offline Model",
+        tier: .standard,
+        remoteURL: URL(string: "https://models.filesmind.test/offline.safetensors")!,
+        sha256: SHA256.hash(data: payload).map { String(format: "%02x", $0) }.joined(),
+        bytes: Int64(payload.count)
+    )
+
+    let configuration = URLSessionConfiguration.ephemeral
+    configuration.protocolClasses = [RangeServingURLProtocol.self]
+    let network = ScriptedNetworkStatus(isOnline: false)
+    let manager = DefaultModelManager(
+        catalog: StaticModelCatalog(models: [descriptor]),
+        validator: SHA256ArtifactValidator(),
+        installRoot: installRoot,
+        session: URLSession(configuration: configuration),
+        networkStatus: network
+    )
+
+    let install = Task { try await manager.install(modelID: descriptor.modelID, progress: nil) }
+    try await Task.sleep(for: .milliseconds(100))
+    #expect(RangeServingURLProtocol.requestedRanges(for: "/offline.safetensors").isEmpty)
+
+    await network.setOnline(true)
+    let installedURL = try await install.value
+
+    #expect(RangeServingURLProtocol.requestedRanges(for: "/offline.safetensors") == [nil])
+    #expect(try Data(contentsOf: installedURL) == payload)
+}
+
+@Test("ModelDownloadProgress should derive fraction and remaining time")
+func modelDownloadProgressEstimates() {
+    let progress = ModelDownloadProgress(modelID: "m", receivedBytes: 250, totalBytes: 1_000, bytesPerSecond: 50)
+
+    #expect(progress.fractionCompleted == 0.25)
+    #expect(progress.estimatedTimeRemaining == 15)
+}
+
+private actor ScriptedNetworkStatus: NetworkStatusProviding {
+    private var status: NetworkStatus
+    private var continuations: [AsyncStream<NetworkStatus>.Continuation] = []
+
+    init(isOnline: Bool) {
+        self.status = NetworkStatus(isOnline: isOnline)
+    }
+
+    func currentStatus() -> NetworkStatus {
+        status
+    }
+
+    func statusUpdates() -> AsyncStream<NetworkStatus> {
+        AsyncStream { continuation in
+            continuations.append(continuation)
+            continuation.yield(status)
+        }
+    }
+
+    func setOnline(_ isOnline: Bool) {
+        status = NetworkStatus(isOnline: isOnline)
+        for continuation in continuations {
+            continuation.yield(status)
+        }
+    }
+}
+
+private final class RangeServingURLProtocol: URLProtocol, @unchecked Sendable {
+    private static let lock = NSLock()
+    nonisolated(unsafe) private static var payloads: [String: Data] = [:]
+    nonisolated(unsafe) private static var ranges: [String: [String?]] = [:]
+    nonisolated(unsafe) private static var chunkSizes: [String: Int] = [:]
+
+    // Keyed by path so tests running in parallel don't see each other's requests.
+    /// With a `chunkSize`, the body is handed to the client in pieces of that size instead of all at once.
+    static func serve(_ payload: Data, at path: String, chunkSize: Int? = nil) {
+        lock.withLock {
+            payloads[path] = payload
+            ranges[path] = []
+            chunkSizes[path] = chunkSize
+        }
+    }
+
+    static func requestedRanges(for path: String) -> [String?] {
+        lock.withLock { ranges[path] ?? [] }
+    }
+
+    override class func canInit(with request: URLRequest) -> Bool {
+        true
+    }
+
+    override class func canonicalRequest(for request: URLRequest) -> URLRequest {
+        request
+    }
+
+    override func startLoading() {
+        let path = request.url?.path ?? ""
+        let range = request.value(forHTTPHeaderField: "Range")
+        let (payload, chunkSize) = Self.lock.withLock {
+            Self.ranges[path, default: []].append(range)
+            return (Self.payloads[path] ?? Data(), Self.chunkSizes[path])
+        }
+
+        var statusCode = 200
+        var body = payload
+        if let range, range.hasPrefix("bytes="), let start = Int(range.dropFirst("bytes=".count).dropLast()) {
+            statusCode = 206
+            body = payload.subdata(in: start..<payload.count)
+        }
+
+        let response = HTTPURLResponse(
+            url: request.url!,
+            statusCode: statusCode,
+            httpVersion: "HTTP/1.1",
+            headerFields: ["Content-Length": String(body.count)]
+        )!
+        client?.urlProtocol(self, didReceive: response, cacheStoragePolicy: .notAllowed)
+        let step = max(1, chunkSize ?? body.count)
+        for start in stride(from: 0, to: body.count, by: step) {
+            client?.urlProtocol(self, didLoad: body.subdata(in: start..<min(start + step, body.count)))
+        }
+        client?.urlProtocolDidFinishLoading(self)
+    }
+
+    override func stopLoading() {}
+}
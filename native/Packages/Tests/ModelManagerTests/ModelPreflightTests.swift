@@ -0,0 +1,80 @@
+import Domain
+import Foundation
+import ModelManager
+import Testing
+
+@Test("ModelPreflightEvaluator should flag insufficient memory and disk")
+func preflightShouldFlagInsufficientResources() {
+    let descriptor = makeDescriptor(bytes: 4_000_000_000, minimumMemoryBytes: 16_000_000_000)
+    let profile = makeProfile(physicalMemory: 8_000_000_000, availableMemory: 4_000_000_000, availableDisk: 1_000_000_000)
+
+    let report = ModelPreflightEvaluator.evaluate(descriptor: descriptor, profile: profile)
+
+    #expect(report.meetsRequirements == false)
+    #expect(report.issues == [
+        .insufficientMemory(requiredBytes: 16_000_000_000, physicalBytes: 8_000_000_000),
+        .insufficientDisk(requiredBytes: 4_000_000_000, availableBytes: 1_000_000_000)
+    ])
+}
+
+@Test("ModelPreflightEvaluator should only warn when available memory is low")
+func preflightShouldWarnOnLowAvailableMemory() {
+    let descriptor = makeDescriptor(bytes: 1_000_000_000, minimumMemoryBytes: 8_000_000_000)
+    let profile = makeProfile(physicalMemory: 16_000_000_000, availableMemory: 2_000_000_000, availableDisk: 50_000_000_000)
+
+    let report = ModelPreflightEvaluator.evaluate(descriptor: descriptor, profile: profile)
+
+    #expect(report.meetsRequirements == true)
+    #expect(report.issues == [.lowAvailableMemory(requiredBytes: 8_000_000_000, availableBytes: 2_000_000_000)])
+}
+
+@Test("DefaultModelManager preflight should use the injected profiler")
+func modelManagerPreflightUsesProfiler() async throws {
+    let descriptor = makeDescriptor(bytes: 1_000, minimumMemoryBytes: 0)
+    let installRoot = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-models-\(UUID().uuidString)", isDirectory: true)
+    let manager = DefaultModelManager(
+        catalog: StaticModelCatalog(models: [descriptor]),
+        validator: SHA256ArtifactValidator(),
+        installRoot: installRoot,
+        systemProfiler: FixedSystemProfiler(
+            profile: makeProfile(physicalMemory: 8_000_000_000, availableMemory: nil, availableDisk: 500)
+        )
+    )
+
+    let report = try await manager.preflight(modelID: descriptor.modelID)
+    #expect(report.modelID == descriptor.modelID)
+    #expect(report.issues == [.insufficientDisk(requiredBytes: 1_000, availableBytes: 500)])
+}
+
+private struct FixedSystemProfiler: SystemProfiling {
+    let profile: SystemProfile
+
+    func currentProfile(volumeURL: URL) async throws -> SystemProfile {
+        profile
+    }
+}
+
+private func makeDescriptor(bytes: Int64, minimumMemoryBytes: Int64) -> ModelDescriptor {
+    ModelDescriptor(
+        modelID: "test-model",
+        displayName: "Test Model",
+        tier: .standard,
+        remoteURL: URL(string: "https://example.com/model.safetensors")!,
+        sha256: "",
+        bytes: bytes,
+        minimumMemoryBytes: minimumMemoryBytes
+    )
+}
+
+private func makeProfile(physicalMemory: UInt64, availableMemory: UInt64?, availableDisk: Int64?) -> SystemProfile {
+    SystemProfile(
+        cpuModel: "Test CPU",
+        physicalCoreCount: 8,
+        activeCoreCount: 8,
+        physicalMemoryBytes: physicalMemory,
+        availableMemoryBytes: availableMemory,
+        availableDiskBytes: availableDisk,
+        osVersion: "Test OS"
+    )
+}
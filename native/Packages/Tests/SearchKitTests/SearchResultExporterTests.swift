@@ -0,0 +1,93 @@
+import Domain
+import Foundation
+import SearchKit
+import Testing
+
+private func makeResults(documentID: UUID) -> [RankedChunk] {
+    [
+        RankedChunk(chunk: Chunk(documentID: documentID, ordinal: 0, text: "plain text"), score: 1.0),
+        RankedChunk(
+            chunk: Chunk(documentID: documentID, ordinal: 3, text: "quoted \"value\", with comma\nand newline", sourcePageIndex: 1),
+            score: 0.5
+        )
+    ]
+}
+
+private func makeDocument(id: UUID) -> ImportedDocumentRecord {
+    ImportedDocumentRecord(
+        id: id,
+        sourcePath: "/tmp/notes.pdf",
+        title: "Notes",
+        sourceType: .pdf,
+        chunkCount: 4,
+        lowQualityPages: [],
+        importedAt: Date()
+    )
+}
+
+private func makeDestination(_ name: String) throws -> URL {
+    let dir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-export-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+    return dir.appendingPathComponent(name, isDirectory: false)
+}
+
+@Test("SearchResultExporter should write escaped CSV rows")
+func exporterWritesCSV() async throws {
+    let documentID = UUID()
+    let destination = try makeDestination("results.csv")
+
+    let written = try await SearchResultExporter(batchSize: 1).export(
+        makeResults(documentID: documentID),
+        documents: [documentID: makeDocument(id: documentID)],
+        format: .csv,
+        to: destination
+    )
+
+    let contents = try String(contentsOf: destination, encoding: .utf8)
+    #expect(written == 2)
+    #expect(contents.hasPrefix("rank,score,document_id,document_title,source_path,chunk_id,ordinal,page,text\n"))
+    #expect(contents.contains(",Notes,/tmp/notes.pdf,"))
+    #expect(contents.contains(",3,2,\"quoted \"\"value\"\", with comma\nand newline\"\n"))
+}
+
+@Test("SearchResultExporter should write one JSON object per line and report progress")
+func exporterWritesJSONLines() async throws {
+    let documentID = UUID()
+    let destination = try makeDestination("results.jsonl")
+    let reported = ProgressRecorder()
+
+    try await SearchResultExporter(batchSize: 1).export(
+        makeResults(documentID: documentID),
+        format: .jsonl,
+        to: destination
+    ) { progress in
+        reported.append(progress.writtenRows)
+    }
+
+    let lines = try String(contentsOf: destination, encoding: .utf8)
+        .split(separator: "\n")
+    #expect(lines.count == 2)
+
+    let second = try JSONSerialization.jsonObject(with: Data(lines[1].utf8)) as? [String: Any]
+    #expect(second?["rank"] as? Int == 2)
+    #expect(second?["page"] as? Int == 2)
+    #expect(second?["text"] as? String == "quoted \"value\", with comma\nand newline")
+    #expect(reported.values.last == 2)
+
+    let leftovers = try FileManager.default.contentsOfDirectory(atPath: destination.deletingLastPathComponent().path)
+    #expect(leftovers == ["results.jsonl"])
+}
+
+private final class ProgressRecorder: @unchecked Sendable {
+    private let lock = NSLock()
+    private var storage: [Int] = []
+
+    func append(_ value: Int) {
+        lock.withLock { storage.append(value) }
+    }
+
+    var values: [Int] {
+        lock.withLock { storage }
+    }
+}
@@ -0,0 +1,34 @@
+import Domain
+import SecurityKit
+import Foundation
+import Testing
+
+@Test("DocumentPasswordVault should keep session passwords per file")
+func documentPasswordVaultSessionRoundtrip() async throws {
+    let vault = DocumentPasswordVault(usesKeychain: false)
+    let fileURL = URL(fileURLWithPath: "/tmp/locked.pdf")
+    let otherURL = URL(fileURLWithPath: "/tmp/other.pdf")
+
+    try await vault.setPassword("secret", for: fileURL, persist: false)
+
+    #expect(try await vault.password(for: fileURL) == "secret")
+    #expect(try await vault.password(for: otherURL) == nil)
+
+    try await vault.removePassword(for: fileURL)
+    #expect(try await vault.password(for: fileURL) == nil)
+}
+
+@Test("DocumentPasswordVault should forget every password at once")
+func documentPasswordVaultRemovesAllPasswords() async throws {
+    let vault = DocumentPasswordVault(usesKeychain: false)
+    let fileURLs = [URL(fileURLWithPath: "/tmp/first.pdf"), URL(fileURLWithPath: "/tmp/second.pdf")]
+    for fileURL in fileURLs {
+        try await vault.setPassword("secret", for: fileURL, persist: false)
+    }
+
+    try await vault.removeAllPasswords()
+
+    for fileURL in fileURLs {
+        #expect(try await vault.password(for: fileURL) == nil)
+    }
+}
@@ -0,0 +1,14 @@
+import Foundation
+import SecurityKit
+import Testing
+
+@Test("FileHasher should stream SHA-256 across small read blocks")
+func fileHasherStreamsSHA256() throws {
+    let url = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-hash-\(UUID().uuidString).txt", isDirectory: false)
+    try "abc".write(to: url, atomically: true, encoding: .utf8)
+
+    let digest = try FileHasher(bufferSize: 1).sha256(of: url)
+
+    #expect(digest == "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")
+}
@@ -0,0 +1,34 @@
+import Foundation
+import SecurityKit
+import Testing
+
+@Test("SecureFileEraser should overwrite contents in place before deleting the file")
+func secureFileEraserOverwritesThenDeletes() throws {
+    let root = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-erase-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: root, withIntermediateDirectories: true)
+    let fileURL = root.appendingPathComponent("confidential.md")
+    let secret = Data(String(repeating: "top secret ", count: 100).utf8)
+    try secret.write(to: fileURL)
+
+    // A hard link shares the file's blocks, so it shows what the eraser wrote after the name is gone.
+    let witnessURL = root.appendingPathComponent("witness.md")
+    try FileManager.default.linkItem(at: fileURL, to: witnessURL)
+
+    try SecureFileEraser(bufferSize: 64).erase(fileURL)
+
+    #expect(!FileManager.default.fileExists(atPath: fileURL.path))
+    #expect(try Data(contentsOf: witnessURL) == Data(count: secret.count))
+}
+
+@Test("SecureFileEraser should refuse directories")
+func secureFileEraserRejectsDirectories() throws {
+    let directory = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-erase-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: directory, withIntermediateDirectories: true)
+
+    #expect(throws: (any Error).self) {
+        try SecureFileEraser().erase(directory)
+    }
+    #expect(FileManager.default.fileExists(atPath: directory.path))
+}
@@ -29,4 +29,9 @@ func authorizeAndResolveWorkspace() async throws {
     let handle = try await manager.startScopedAccess(id: workspaceID)
     #expect(handle.workspaceID == workspaceID)
     await manager.stopScopedAccess(handle)
+
+    try await manager.revokeAuthorization(id: workspaceID)
+    await #expect(throws: FilesMindError.self) {
+        try await manager.resolveAuthorization(id: workspaceID)
+    }
 }
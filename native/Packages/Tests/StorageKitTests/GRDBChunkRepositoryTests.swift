@@ -48,3 +48,164 @@ func grdbRepositoryPersistsAndSearches() async throws {
     #expect(loadedSections.count == 2)
     #expect(loadedSections.map(\.title) == ["Heading 1", "Heading 2"])
 }
+
+@Test("GRDBChunkRepository should persist failed imports")
+func grdbRepositoryPersistsFailedImports() async throws {
+    let tempDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-grdb-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+
+    let dbURL = tempDir.appendingPathComponent("chunks.sqlite", isDirectory: false)
+    let repository = try GRDBChunkRepository(databaseURL: dbURL)
+
+    let record = FailedImportRecord(
+        id: UUID(),
+        fileURL: URL(fileURLWithPath: "/tmp/broken.pdf"),
+        priority: .watchedFolder,
+        attemptCount: 3,
+        lastError: "Unable to open PDF"
+    )
+
+    try await repository.upsertFailedImport(record)
+    let reopened = try GRDBChunkRepository(databaseURL: dbURL)
+    let loaded = try await reopened.failedImports()
+    #expect(loaded.count == 1)
+    #expect(loaded.first?.id == record.id)
+    #expect(loaded.first?.priority == .watchedFolder)
+    #expect(loaded.first?.fileURL.path == "/tmp/broken.pdf")
+
+    try await reopened.removeFailedImport(id: record.id)
+    let afterRemove = try await reopened.failedImports()
+    #expect(afterRemove.isEmpty)
+}
+
+@Test("GRDBChunkRepository should match CJK text across width and script variants")
+func grdbRepositorySearchesNormalizedCJK() async throws {
+    let tempDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-grdb-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+
+    let dbURL = tempDir.appendingPathComponent("chunks.sqlite", isDirectory: false)
+    let repository = try GRDBChunkRepository(databaseURL: dbURL)
+
+    let documentID = UUID()
+    try await repository.upsert([
+        Chunk(documentID: documentID, ordinal: 0, text: "知识图谱 v2 release"),
+        Chunk(documentID: documentID, ordinal: 1, text: "ｶﾀｶﾅ memo")
+    ])
+
+    let simplifiedHits = try await repository.search(byKeyword: "知識圖譜", limit: 10)
+    #expect(simplifiedHits.map(\.ordinal) == [0])
+
+    let widthHits = try await repository.search(byKeyword: "Ｖ２", limit: 10)
+    #expect(widthHits.map(\.ordinal) == [0])
+
+    let katakanaHits = try await repository.search(byKeyword: "カタカナ", limit: 10)
+    #expect(katakanaHits.map(\.ordinal) == [1])
+}
+
+@Test("GRDBChunkRepository should remove a document with its chunks and sections")
+func grdbRepositoryRemovesDocuments() async throws {
+    let tempDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-grdb-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+
+    let dbURL = tempDir.appendingPathComponent("chunks.sqlite", isDirectory: false)
+    let repository = try GRDBChunkRepository(databaseURL: dbURL)
+
+    let removedID = UUID()
+    let keptID = UUID()
+    try await repository.upsert([
+        Chunk(documentID: removedID, ordinal: 0, text: "shared keyword"),
+        Chunk(documentID: keptID, ordinal: 0, text: "shared keyword")
+    ])
+    for id in [removedID, keptID] {
+        let record = ImportedDocumentRecord(
+            id: id,
+            sourcePath: "/tmp/\(id.uuidString).md",
+            title: id.uuidString,
+            sourceType: .markdown,
+            chunkCount: 1,
+            lowQualityPages: [],
+            importedAt: Date()
+        )
+        let sections = [ParsedSection(documentID: id, level: 1, title: "Heading", chunkStartOrdinal: 0)]
+        try await repository.upsertDocument(record, sections: sections)
+    }
+
+    try await repository.removeDocument(id: removedID)
+
+    let hits = try await repository.search(byKeyword: "keyword", limit: 10)
+    #expect(hits.map(\.documentID) == [keptID])
+    #expect(try await repository.recentDocuments(limit: 10).map(\.id) == [keptID])
+    #expect(try await repository.sections(for: removedID).isEmpty)
+}
+
+@Test("GRDBChunkRepository should write a backup that opens as a full copy")
+func grdbRepositoryWritesBackups() async throws {
+    let tempDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-grdb-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+
+    let repository = try GRDBChunkRepository(databaseURL: tempDir.appendingPathComponent("chunks.sqlite"))
+    let documentID = UUID()
+    try await repository.upsert([Chunk(documentID: documentID, ordinal: 0, text: "backed up keyword")])
+
+    let backupURL = tempDir.appendingPathComponent("Backups/copy.sqlite", isDirectory: false)
+    try await repository.writeBackup(to: backupURL)
+
+    let restored = try GRDBChunkRepository(databaseURL: backupURL)
+    let hits = try await restored.search(byKeyword: "keyword", limit: 10)
+    #expect(hits.map(\.documentID) == [documentID])
+}
+
+@Test("GRDBChunkRepository should restore a backup and reject files that are not backups")
+func grdbRepositoryRestoresBackups() async throws {
+    let tempDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-grdb-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+
+    let dbURL = tempDir.appendingPathComponent("chunks.sqlite", isDirectory: false)
+    let repository = try GRDBChunkRepository(databaseURL: dbURL)
+    let originalID = UUID()
+    try await repository.upsert([Chunk(documentID: originalID, ordinal: 0, text: "restore keyword")])
+
+    let backupURL = tempDir.appendingPathComponent("backup.sqlite", isDirectory: false)
+    try await repository.writeBackup(to: backupURL)
+    try await repository.upsert([Chunk(documentID: UUID(), ordinal: 0, text: "later keyword")])
+
+    let bogusURL = tempDir.appendingPathComponent("bogus.sqlite", isDirectory: false)
+    try Data("not a database".utf8).write(to: bogusURL)
+    await #expect(throws: FilesMindError.self) {
+        try await repository.restoreBackup(from: bogusURL)
+    }
+    #expect(try await repository.search(byKeyword: "keyword", limit: 10).count == 2)
+
+    try await repository.restoreBackup(from: backupURL)
+
+    let hits = try await repository.search(byKeyword: "keyword", limit: 10)
+    #expect(hits.map(\.documentID) == [originalID])
+    #expect(FileManager.default.fileExists(atPath: tempDir.appendingPathComponent("chunks.pre-restore.sqlite").path))
+}
+
+@Test("GRDBChunkRepository should erase the whole library and stay usable")
+func grdbRepositoryErasesLibrary() async throws {
+    let tempDir = FileManager.default.temporaryDirectory
+        .appendingPathComponent("filesmind-grdb-\(UUID().uuidString)", isDirectory: true)
+    try FileManager.default.createDirectory(at: tempDir, withIntermediateDirectories: true)
+
+    let dbURL = tempDir.appendingPathComponent("chunks.sqlite", isDirectory: false)
+    let repository = try GRDBChunkRepository(databaseURL: dbURL)
+    try await repository.upsert([Chunk(documentID: UUID(), ordinal: 0, text: "erased keyword")])
+    let backupURL = tempDir.appendingPathComponent("backup.sqlite", isDirectory: false)
+    try await repository.writeBackup(to: backupURL)
+    try await repository.restoreBackup(from: backupURL)
+
+    try await repository.eraseLibrary()
+
+    #expect(try await repository.search(byKeyword: "keyword", limit: 10).isEmpty)
+    #expect(!FileManager.default.fileExists(atPath: tempDir.appendingPathComponent("chunks.pre-restore.sqlite").path))
+    let documentID = UUID()
+    try await repository.upsert([Chunk(documentID: documentID, ordinal: 0, text: "fresh keyword")])
+    #expect(try await repository.search(byKeyword: "keyword", limit: 10).map(\.documentID) == [documentID])
+}